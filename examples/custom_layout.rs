@@ -0,0 +1,104 @@
+use thiserror::Error;
+
+use bevy::color::palettes;
+use bevy::prelude::*;
+
+use bevy_anyhow_alert::{AlertMessage, AlertsPlugin, AnyhowAlertExt};
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins);
+    app.add_plugins(AlertsPlugin::new().with_builder(build_alert));
+
+    app.add_systems(Startup, init);
+    app.add_systems(Update, fire_error.anyhow_alert());
+
+    app.run();
+}
+
+fn build_alert(body: &mut ChildBuilder, message: &AlertMessage) {
+    body.spawn(NodeBundle {
+        style: Style {
+            column_gap: Val::Px(8.),
+            align_items: AlignItems::Center,
+            ..Default::default()
+        },
+        ..Default::default()
+    })
+    .with_children(|row| {
+        // the icon
+        row.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(32.),
+                height: Val::Px(32.),
+                ..Default::default()
+            },
+            background_color: Color::Srgba(palettes::css::CRIMSON).into(),
+            border_radius: BorderRadius::all(Val::Percent(50.)),
+            ..Default::default()
+        });
+        row.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .with_children(|lines| {
+            lines.spawn(TextBundle::from_section(
+                "Something went wrong",
+                TextStyle {
+                    font_size: 20.,
+                    color: Color::BLACK,
+                    ..Default::default()
+                },
+            ));
+            lines.spawn(TextBundle::from_section(
+                message.0.clone(),
+                TextStyle {
+                    font_size: 16.,
+                    color: Color::Srgba(palettes::css::DIM_GRAY),
+                    ..Default::default()
+                },
+            ));
+        });
+    });
+}
+
+fn init(mut commands: Commands) {
+    commands.spawn((Camera2dBundle::default(), IsDefaultUiCamera));
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                width: Val::Percent(100.),
+                height: Val::Percent(100.),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            background_color: Color::Srgba(palettes::css::ANTIQUE_WHITE).into(),
+            ..Default::default()
+        })
+        .with_children(|builder| {
+            builder.spawn(TextBundle::from_section(
+                "Press Space to fire error toast",
+                TextStyle {
+                    font_size: 48.,
+                    color: Color::BLACK,
+                    ..Default::default()
+                },
+            ));
+        });
+}
+
+#[derive(Debug, Error)]
+#[error("the save file is corrupted")]
+pub struct SaveError;
+
+fn fire_error(inputs: Res<ButtonInput<KeyCode>>) -> anyhow::Result<()> {
+    if inputs.just_pressed(KeyCode::Space) {
+        Err(anyhow::Error::new(SaveError))
+    } else {
+        Ok(())
+    }
+}
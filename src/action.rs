@@ -0,0 +1,36 @@
+use bevy_ecs::{prelude::*, system::SystemId};
+
+/// A button shown under the message of an alert, running a one-shot system when pressed and
+/// then dismissing the alert.
+///
+/// The system is registered with `World::register_system`; the button is hidden once the system
+/// is removed.
+#[derive(Clone, Debug)]
+pub struct AlertAction {
+    /// The text of the button.
+    pub label: String,
+    /// The system run when the button is pressed.
+    pub system: SystemId,
+}
+
+impl AlertAction {
+    /// Builds an action labeled `label` running `system`.
+    pub fn new(label: impl Into<String>, system: SystemId) -> Self {
+        Self {
+            label: label.into(),
+            system,
+        }
+    }
+}
+
+/// The [`AlertAction`]s of an alert, inserted on alert entities raised with actions.
+#[derive(Clone, Debug, Component)]
+pub struct AlertActions(pub Vec<AlertAction>);
+
+/// The button of an [`AlertAction`] of `alert`.
+#[cfg(feature = "ui")]
+#[derive(Debug, Component)]
+pub struct AlertActionButton {
+    pub(crate) alert: Entity,
+    pub(crate) system: SystemId,
+}
@@ -0,0 +1,741 @@
+#[cfg(feature = "ui")]
+use std::collections::HashMap;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy_core::FrameCount;
+use bevy_ecs::{
+    prelude::*,
+    system::{EntityCommands, SystemParam},
+};
+use bevy_time::{Real, Time};
+use bevy_utils::tracing::{error, info, warn};
+
+use crate::{
+    ActiveAlertBackend, AlertAction, AlertChannels, AlertConfig, AlertHistory, AlertL10n,
+    AlertReport, AlertReporters, AlertStyle, AlertStyleRegistry, ErrorStats, Formatted,
+    IntoMessage, MessageCache, RaisedAlert, SuppressedAlerts, ToAlertMessage, LOG_TARGET,
+};
+
+/// How severe the problem behind an alert is.
+///
+/// This component is inserted on every alert entity spawned by this crate, so styling systems
+/// can query it to render alerts differently.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    /// Something the user may want to know about.
+    Info,
+    /// Something went wrong, but the application can recover.
+    Warning,
+    /// Something went wrong. This is the severity of [`AnyhowAlertExt::anyhow_alert`].
+    ///
+    /// [`AnyhowAlertExt::anyhow_alert`]: crate::AnyhowAlertExt::anyhow_alert
+    #[default]
+    Error,
+}
+
+/// The number of times an alert's message was raised while the alert was alive.
+///
+/// When [`AlertConfig::dedup`] is enabled, raising a message that is already shown bumps this
+/// counter instead of spawning another alert.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub struct AlertCount(pub usize);
+
+/// When an alert was raised.
+///
+/// Inserted on every alert entity. When [`AlertConfig::dedup`] folds a repeated message into
+/// the alert, only `last_seen` is updated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub struct RaisedAt {
+    /// The [`Time<Real>`] elapsed when the alert was first raised.
+    pub real_time: Duration,
+    /// The [`FrameCount`] when the alert was first raised.
+    pub frame: u32,
+    /// The [`Time<Real>`] elapsed when the alert was last raised.
+    pub last_seen: Duration,
+}
+
+/// The entity an alert was raised for.
+///
+/// Inserted on alert entities raised with a source, such as those raised by targeted
+/// [`ErrorRaised`](crate::ErrorRaised) triggers.
+///
+/// The alert outlives its source, so the entity may have been despawned since; look it up with
+/// `Query::get` or `World::get_entity` instead of assuming it exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub struct AlertSource(pub Entity);
+
+impl RaisedAt {
+    /// The [`RaisedAt::real_time`], formatted like `"[00:03:45]"`.
+    pub fn timestamp(&self) -> String {
+        let seconds = self.real_time.as_secs();
+        let (hours, minutes) = (seconds / 3600, seconds / 60 % 60);
+        format!("[{hours:02}:{minutes:02}:{:02}]", seconds % 60)
+    }
+}
+
+/// The message of an alert, without any decorations added to its text.
+///
+/// Inserted on every alert entity.
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct AlertMessage(pub String);
+
+/// How long an alert stays shown before it is dismissed.
+///
+/// Inserted on alert entities from [`AlertConfig::ttl`] when they are raised, unless the pipe
+/// overrides it (see [`AnyhowAlertExt::anyhow_alert_ttl`]). Alerts without this component are
+/// never dismissed automatically, and alerts with a zero `Ttl` are despawned on the frame after
+/// they are shown.
+///
+/// The time is measured in [`Time<Real>`], so alerts still expire while the virtual time of the
+/// game is paused.
+///
+/// [`AnyhowAlertExt::anyhow_alert_ttl`]: crate::AnyhowAlertExt::anyhow_alert_ttl
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub struct Ttl(pub Duration);
+
+/// Marks an alert that stays shown until the user dismisses it or `ClearAlerts` is sent.
+///
+/// Sticky alerts are pinned above the other alerts. See [`AnyhowAlertExt::anyhow_alert_sticky`].
+///
+/// [`AnyhowAlertExt::anyhow_alert_sticky`]: crate::AnyhowAlertExt::anyhow_alert_sticky
+#[derive(Clone, Copy, Debug, Default, Component)]
+pub struct Sticky;
+
+/// How important an alert is compared to the others.
+///
+/// Higher priority alerts are shown first when more than `AlertConfig::max_visible` alerts
+/// are raised, replace the lowest priority alert shown when the stack is full, and are pinned
+/// above lower priority alerts (but below [`Sticky`] ones). Alerts with the same priority are
+/// ordered by `AlertConfig::stack`.
+///
+/// Defaults to the priority of the [`Severity`] of the alert (0 for info, 1 for warnings and 2
+/// for errors); see [`AnyhowAlertExt::anyhow_alert_priority`] to override it.
+///
+/// [`AnyhowAlertExt::anyhow_alert_priority`]: crate::AnyhowAlertExt::anyhow_alert_priority
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Component)]
+pub struct AlertPriority(pub i32);
+
+impl From<Severity> for AlertPriority {
+    fn from(severity: Severity) -> Self {
+        Self(match severity {
+            Severity::Info => 0,
+            Severity::Warning => 1,
+            Severity::Error => 2,
+        })
+    }
+}
+
+/// The backtrace of the code that raised an alert.
+///
+/// Inserted on alert entities and stored in the [`AlertHistory`] when the `backtrace` feature
+/// is enabled. Like [`std::backtrace::Backtrace::capture`], the backtrace is only resolved when
+/// the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment variables enable it.
+#[cfg(feature = "backtrace")]
+#[derive(Clone, Debug, Component)]
+pub struct Backtrace(pub std::sync::Arc<std::backtrace::Backtrace>);
+
+#[cfg(feature = "backtrace")]
+impl Backtrace {
+    /// Captures the backtrace of the caller.
+    pub fn capture() -> Self {
+        Self(std::sync::Arc::new(std::backtrace::Backtrace::capture()))
+    }
+
+    /// A backtrace that wasn't captured.
+    pub fn disabled() -> Self {
+        Self(std::sync::Arc::new(std::backtrace::Backtrace::disabled()))
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl PartialEq for Backtrace {
+    /// Backtraces are equal if they are the same capture.
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl Eq for Backtrace {}
+
+/// The error an alert was raised for.
+///
+/// Inserted on alert entities raised by the pipes for single errors, such as
+/// [`AnyhowAlertExt::anyhow_alert`], so systems can inspect the error behind an alert. The error
+/// is moved into the component, so it doesn't need to implement `Clone`; only the first error of
+/// alerts folded by [`AlertConfig::dedup`] is kept.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn offer_retry(alerts: Query<&ErrorPayload<anyhow::Error>, Added<ErrorPayload<anyhow::Error>>>) {
+///     for ErrorPayload(error) in &alerts {
+///         if error.is::<std::io::Error>() {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`AnyhowAlertExt::anyhow_alert`]: crate::AnyhowAlertExt::anyhow_alert
+#[derive(Debug, Component)]
+pub struct ErrorPayload<E: Send + Sync + 'static>(pub E);
+
+/// The support code and help page of an alert.
+///
+/// Inserted on alert entities raised by [`AnyhowAlertExt::anyhow_alert_meta`] or
+/// [`AnyhowAlertExt::anyhow_alert_coded`], whose message starts with the code (as in
+/// `"E1042: save failed"`). The help page isn't opened by this crate; query the component to link
+/// it from your own UI.
+///
+/// [`AnyhowAlertExt::anyhow_alert_meta`]: crate::AnyhowAlertExt::anyhow_alert_meta
+/// [`AnyhowAlertExt::anyhow_alert_coded`]: crate::AnyhowAlertExt::anyhow_alert_coded
+#[derive(Clone, Debug, Default, PartialEq, Eq, Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlertMeta {
+    /// The support code of the error, such as `"E1042"`.
+    pub code: Option<String>,
+    /// The address of the help page of the error.
+    pub help_url: Option<String>,
+}
+
+impl AlertMeta {
+    /// Builds the meta of an error with the support code `code`.
+    pub fn code(code: impl Into<String>) -> Self {
+        Self {
+            code: Some(code.into()),
+            help_url: None,
+        }
+    }
+
+    /// Links the help page at `help_url`.
+    pub fn with_help_url(mut self, help_url: impl Into<String>) -> Self {
+        self.help_url = Some(help_url.into());
+        self
+    }
+}
+
+/// Implemented by error types with an [`AlertMeta`], see
+/// [`AnyhowAlertExt::anyhow_alert_coded`](crate::AnyhowAlertExt::anyhow_alert_coded).
+///
+/// ```
+/// use bevy_anyhow_alert::*;
+///
+/// #[derive(Debug, thiserror::Error)]
+/// #[error("save failed")]
+/// struct SaveError;
+///
+/// impl HasAlertMeta for SaveError {
+///     fn alert_meta(&self) -> AlertMeta {
+///         AlertMeta::code("E1042").with_help_url("https://example.com/wiki/E1042")
+///     }
+/// }
+/// ```
+pub trait HasAlertMeta {
+    /// The meta of the alert raised for this error.
+    fn alert_meta(&self) -> AlertMeta;
+}
+
+type InsertPayload = dyn Fn(&mut EntityCommands) + Send + Sync;
+
+/// The error of a [`RaisedAlert`], inserted as an [`ErrorPayload`] on the alert entity.
+///
+/// Clones share the error, which is only inserted on the first entity.
+#[derive(Clone)]
+pub struct AlertPayload(Arc<InsertPayload>);
+
+impl AlertPayload {
+    /// Wraps `error` to be inserted as an [`ErrorPayload<E>`].
+    pub fn new<E: Send + Sync + 'static>(error: E) -> Self {
+        let error = Mutex::new(Some(error));
+        Self(Arc::new(move |alert| {
+            let error = error.lock().ok().and_then(|mut error| error.take());
+            if let Some(error) = error {
+                alert.insert(ErrorPayload(error));
+            }
+        }))
+    }
+
+    /// Inserts the error on `alert`, unless it was already inserted.
+    pub fn insert(&self, alert: &mut EntityCommands) {
+        (self.0)(alert);
+    }
+}
+
+impl std::fmt::Debug for AlertPayload {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlertPayload").finish_non_exhaustive()
+    }
+}
+
+/// When an alert raised through [`Alerts`] expires.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) enum Expiry {
+    /// After [`AlertConfig::ttl`].
+    #[default]
+    Config,
+    /// After the given duration; [`Duration::MAX`] never expires.
+    After(Duration),
+    /// Never, see [`Sticky`].
+    Sticky,
+}
+
+/// How an alert raised through [`Alerts`] differs from the defaults.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RaiseOptions {
+    pub(crate) expiry: Expiry,
+    pub(crate) source: Option<Entity>,
+    pub(crate) style: Option<AlertStyle>,
+    pub(crate) channel: Option<String>,
+    pub(crate) tags: Vec<AlertTag>,
+    pub(crate) payload: Option<AlertPayload>,
+    /// The messages summarized by the raised ones, recorded in the history and stats instead.
+    pub(crate) summarized: Vec<Arc<str>>,
+    /// How many times each message was raised at once, or `None` for once.
+    pub(crate) count: Option<usize>,
+    pub(crate) meta: Option<AlertMeta>,
+    pub(crate) actions: Vec<AlertAction>,
+    pub(crate) modal: bool,
+    pub(crate) priority: Option<i32>,
+    pub(crate) l10n: Option<AlertL10n>,
+}
+
+/// Inserts a marker component on alert entities, see [`AlertOptions::with_tag`].
+#[derive(Clone, Copy)]
+pub struct AlertTag(fn(&mut EntityCommands));
+
+impl AlertTag {
+    /// Builds a tag inserting `M::default()`.
+    pub fn new<M: Component + Default>() -> Self {
+        fn insert<M: Component + Default>(alert: &mut EntityCommands) {
+            alert.insert(M::default());
+        }
+        Self(insert::<M>)
+    }
+
+    /// Inserts the marker component on `alert`.
+    pub fn insert(&self, alert: &mut EntityCommands) {
+        (self.0)(alert);
+    }
+}
+
+impl std::fmt::Debug for AlertTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlertTag").finish_non_exhaustive()
+    }
+}
+
+/// How the alerts raised by [`AnyhowAlertExt::anyhow_alert_with`] differ from the defaults,
+/// combining the settings of the other alert pipes.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// #[derive(Component, Default)]
+/// struct AudioAlert;
+///
+/// fn play_music() -> anyhow::Result<()> { /* ... */ Ok(()) }
+///
+/// let options = AlertOptions::new()
+///     .with_severity(Severity::Warning)
+///     .with_channel("audio")
+///     .with_tag::<AudioAlert>();
+/// let mut app = App::new();
+/// app.add_systems(Update, play_music.anyhow_alert_with(options));
+/// ```
+///
+/// [`AnyhowAlertExt::anyhow_alert_with`]: crate::AnyhowAlertExt::anyhow_alert_with
+#[derive(Clone, Debug, Default)]
+pub struct AlertOptions {
+    pub(crate) severity: Severity,
+    pub(crate) raise: RaiseOptions,
+}
+
+impl AlertOptions {
+    /// Builds options raising alerts like [`AnyhowAlertExt::anyhow_alert`].
+    ///
+    /// [`AnyhowAlertExt::anyhow_alert`]: crate::AnyhowAlertExt::anyhow_alert
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Raises alerts with `severity` instead of [`Severity::Error`].
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Keeps alerts shown for `ttl` instead of [`AlertConfig::ttl`]. See [`Ttl`].
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.raise.expiry = Expiry::After(ttl);
+        self
+    }
+
+    /// Raises [`Sticky`] alerts, which stay shown until they are dismissed.
+    pub fn sticky(mut self) -> Self {
+        self.raise.expiry = Expiry::Sticky;
+        self
+    }
+
+    /// Raises alerts with `priority` instead of the priority of their severity. See
+    /// [`AlertPriority`].
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.raise.priority = Some(priority);
+        self
+    }
+
+    /// Raises [`ModalAlert`](crate::ModalAlert)s, which are sticky and block the rest of the UI until they are
+    /// acknowledged.
+    pub fn modal(mut self) -> Self {
+        self.raise.expiry = Expiry::Sticky;
+        self.raise.modal = true;
+        self
+    }
+
+    /// Raises alerts to `channel`, see [`AlertsPlugin::with_channel`].
+    ///
+    /// [`AlertsPlugin::with_channel`]: crate::AlertsPlugin::with_channel
+    pub fn with_channel(mut self, channel: impl Into<String>) -> Self {
+        self.raise.channel = Some(channel.into());
+        self
+    }
+
+    /// Inserts `M::default()` on every alert entity, so the alerts can be queried with
+    /// `With<M>`.
+    ///
+    /// Repeated messages folded into an existing alert don't tag it.
+    pub fn with_tag<M: Component + Default>(mut self) -> Self {
+        self.raise.tags.push(AlertTag::new::<M>());
+        self
+    }
+
+    /// Adds a button running the system of `action`, see [`AlertAction`].
+    ///
+    /// Repeated messages folded into an existing alert keep the actions of that alert.
+    pub fn with_action(mut self, action: AlertAction) -> Self {
+        self.raise.actions.push(action);
+        self
+    }
+}
+
+/// Marks the bar showing how much of the [`Ttl`] of an alert is left, see
+/// [`AlertConfig::show_ttl_bar`].
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, Default, Component)]
+pub struct TtlBar;
+
+/// How long an alert with a [`Ttl`] has been shown, removed once it expires.
+#[cfg(feature = "ui")]
+#[derive(Debug, Default, Component)]
+pub(crate) struct AlertAge(pub(crate) Duration);
+
+/// The severity, message, and channel of an alert, which deduplicated alerts share.
+#[cfg(feature = "ui")]
+pub(crate) type AlertKey = (Severity, Arc<str>, Option<String>);
+
+/// Tracks the alert entity currently showing each message and severity in each channel, for
+/// deduplication.
+#[cfg(feature = "ui")]
+#[derive(Debug, Default, Resource)]
+pub(crate) struct LiveAlerts {
+    pub(crate) alerts: HashMap<AlertKey, (Entity, usize)>,
+}
+
+/// Inserted once the missing [`AlertsPlugin`](crate::AlertsPlugin) has been warned about.
+#[derive(Resource)]
+struct MissingPluginWarned;
+
+/// A [`SystemParam`] that spawns alert entities.
+///
+/// All of the alert pipes in this crate raise their alerts through this parameter, and it can
+/// be used directly to raise alerts without returning from the system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn load_levels(mut alerts: Alerts) {
+///     for level in ["forest", "cave"] {
+///         if let Err(error) = load_level(level) {
+///             alerts.push(error);
+///         }
+///     }
+/// }
+///
+/// fn load_level(name: &str) -> anyhow::Result<()> { /* ... */ Ok(()) }
+/// # let mut app = App::new();
+/// # app.add_systems(Update, load_levels);
+/// ```
+///
+/// If [`AlertsPlugin`](crate::AlertsPlugin) was not added to the app, alerts are logged instead.
+#[derive(SystemParam)]
+pub struct Alerts<'w, 's> {
+    commands: Commands<'w, 's>,
+    config: Option<Res<'w, AlertConfig>>,
+    backend: Option<Res<'w, ActiveAlertBackend>>,
+    warned: Option<Res<'w, MissingPluginWarned>>,
+    history: Option<ResMut<'w, AlertHistory>>,
+    stats: Option<ResMut<'w, ErrorStats>>,
+    time: Option<Res<'w, Time<Real>>>,
+    frame: Option<Res<'w, FrameCount>>,
+    styles: Option<Res<'w, AlertStyleRegistry>>,
+    channels: Option<Res<'w, AlertChannels>>,
+    suppressed: Option<ResMut<'w, SuppressedAlerts>>,
+    reporters: Option<Res<'w, AlertReporters>>,
+    #[cfg(feature = "file_log")]
+    file_sink: Option<Res<'w, crate::FileAlertSink>>,
+    messages: Local<'s, MessageCache>,
+}
+
+impl Alerts<'_, '_> {
+    /// Raises an alert showing `message`.
+    pub fn push(&mut self, message: impl std::fmt::Display) {
+        self.push_with_severity(message, Severity::Error);
+    }
+
+    /// Raises an alert with the given [`Severity`] showing `message`.
+    pub fn push_with_severity(&mut self, message: impl std::fmt::Display, severity: Severity) {
+        self.raise([Formatted(&message)], severity);
+    }
+
+    /// Raises an alert for each message.
+    pub fn extend(&mut self, messages: impl IntoIterator<Item = impl ToAlertMessage>) {
+        self.raise(messages, Severity::Error);
+    }
+
+    /// Raises an alert with `severity` for each message.
+    pub(crate) fn raise(
+        &mut self,
+        messages: impl IntoIterator<Item = impl IntoMessage>,
+        severity: Severity,
+    ) {
+        self.raise_with(messages, severity, RaiseOptions::default());
+    }
+
+    /// Raises an alert with `severity` showing `message`, recording `source` as the entity it
+    /// was raised for. See [`AlertSource`].
+    pub fn push_from(
+        &mut self,
+        message: impl std::fmt::Display,
+        severity: Severity,
+        source: Entity,
+    ) {
+        let options = RaiseOptions {
+            source: Some(source),
+            ..Default::default()
+        };
+        self.raise_with([Formatted(&message)], severity, options);
+    }
+
+    /// The shared message for `message`, see [`ToAlertMessage`].
+    pub(crate) fn message(&mut self, message: impl IntoMessage) -> Arc<str> {
+        message.into_message(&mut self.messages)
+    }
+
+    /// Raises the summary of the [`SuppressedAlerts`] if the alerts were unmuted, see
+    /// [`AlertConfig::mute_summary`].
+    pub(crate) fn summarize_suppressed(&mut self) {
+        let Some(config) = self.config.as_ref() else {
+            return;
+        };
+        let summarize = config.mute_summary;
+        let suppressed = match self.suppressed.as_mut() {
+            Some(suppressed) if !config.muted && suppressed.0 > 0 => {
+                std::mem::take(&mut **suppressed)
+            }
+            _ => return,
+        };
+        if summarize {
+            self.push_with_severity(suppressed.summary(), Severity::Info);
+        }
+    }
+
+    /// The [`AlertStyle`] registered for the type of `error`, if any.
+    pub(crate) fn style_of<Err: 'static>(&self, error: &Err) -> Option<AlertStyle> {
+        self.styles.as_ref().and_then(|styles| styles.get(error))
+    }
+
+    /// Raises an alert with `severity` for each message, which expires according to `expiry`.
+    pub(crate) fn raise_with(
+        &mut self,
+        messages: impl IntoIterator<Item = impl IntoMessage>,
+        severity: Severity,
+        options: RaiseOptions,
+    ) {
+        let RaiseOptions {
+            expiry,
+            source,
+            style,
+            channel,
+            tags,
+            payload,
+            summarized,
+            count,
+            meta,
+            actions,
+            modal,
+            priority,
+            l10n,
+        } = options;
+        // the plugin is the only thing that inserts a backend
+        let Some(backend) = self.backend.as_ref().map(|backend| backend.0.clone()) else {
+            self.log_without_plugin(messages, severity);
+            return;
+        };
+        let default_config = AlertConfig::default();
+        let config = self.config.as_deref().unwrap_or(&default_config);
+        let filtered = severity < config.min_severity;
+        let muted = config.muted;
+        let ttl = match expiry {
+            Expiry::Config => config.ttl,
+            Expiry::After(ttl) => Some(ttl),
+            Expiry::Sticky => None,
+        }
+        .filter(|ttl| *ttl != Duration::MAX);
+        let sticky = matches!(expiry, Expiry::Sticky);
+        let channel = channel.filter(|channel| {
+            let channels = self.channels.as_ref();
+            channels.is_some_and(|channels| channels.0.contains_key(channel))
+        });
+        let now = self
+            .time
+            .as_ref()
+            .map_or(Duration::ZERO, |time| time.elapsed());
+        let raised_at = RaisedAt {
+            real_time: now,
+            frame: self.frame.as_ref().map_or(0, |frame| frame.0),
+            last_seen: now,
+        };
+        #[cfg(feature = "backtrace")]
+        let backtrace = Backtrace::capture();
+        let mut alerts = Vec::new();
+        let code = meta.as_ref().and_then(|meta| meta.code.as_ref());
+        let messages = messages
+            .into_iter()
+            .map(|message| {
+                let message = message.into_message(&mut self.messages);
+                match code {
+                    Some(code) => self.messages.format(format_args!("{code}: {message}")),
+                    None => message,
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut record = |message: &str, times: usize| {
+            if let Some(history) = self.history.as_mut() {
+                for _ in 0..times {
+                    history.record(
+                        message,
+                        severity,
+                        raised_at,
+                        meta.as_ref(),
+                        #[cfg(feature = "backtrace")]
+                        &backtrace,
+                    );
+                }
+            }
+            #[cfg(feature = "file_log")]
+            if let Some(sink) = self.file_sink.as_ref() {
+                // the history folds the alert into its latest entry if it was raised in a row
+                let latest = self
+                    .history
+                    .as_ref()
+                    .and_then(|history| history.iter().next_back());
+                let count = latest.map_or(1, |entry| entry.count);
+                sink.append(message, severity, raised_at, count);
+            }
+            if let Some(stats) = self.stats.as_mut() {
+                for _ in 0..times {
+                    stats.record(message, now);
+                }
+            }
+        };
+        if summarized.is_empty() {
+            // an alert standing for several errors records each of them
+            let times = count.unwrap_or(1);
+            messages.iter().for_each(|message| record(message, times));
+        } else {
+            summarized.iter().for_each(|message| record(message, 1));
+        }
+        for message in messages {
+            alerts.push(RaisedAlert {
+                message,
+                severity,
+                count: count.unwrap_or(1),
+                raised_at,
+                ttl,
+                sticky,
+                source,
+                style,
+                channel: channel.clone(),
+                tags: tags.clone(),
+                payload: payload.clone(),
+                meta: meta.clone(),
+                actions: actions.clone(),
+                modal,
+                priority: priority.unwrap_or(AlertPriority::from(severity).0),
+                l10n: l10n.clone(),
+                #[cfg(feature = "backtrace")]
+                backtrace: backtrace.clone(),
+            });
+        }
+        if filtered || muted {
+            // the alerts are still recorded and reported, but not passed to the backend
+            if let Some(reporters) = self.reporters.as_ref() {
+                for alert in &alerts {
+                    reporters.report(AlertReport::raised(alert));
+                }
+            }
+            if filtered {
+                for alert in &alerts {
+                    log_alert(&alert.message, severity);
+                }
+            } else if let Some(suppressed) = self.suppressed.as_mut() {
+                suppressed.0 += alerts.len();
+            }
+            return;
+        }
+        if !alerts.is_empty() {
+            backend.handle(alerts, &mut self.commands);
+        }
+    }
+
+    fn log_without_plugin(
+        &mut self,
+        messages: impl IntoIterator<Item = impl IntoMessage>,
+        severity: Severity,
+    ) {
+        if self.warned.is_none() {
+            // several systems may get here in the same frame, so check again with world access
+            self.commands.add(|world: &mut World| {
+                if world.contains_resource::<MissingPluginWarned>() {
+                    return;
+                }
+                warn!(
+                    target: LOG_TARGET,
+                    "`AlertsPlugin` was not added to the app, so alerts will be logged instead. \
+                    Add `AlertsPlugin::new()` to your app to show alerts in the UI."
+                );
+                world.insert_resource(MissingPluginWarned);
+            });
+        }
+        for message in messages {
+            let message = message.into_message(&mut self.messages);
+            log_alert(&message, severity);
+        }
+    }
+}
+
+/// Logs `message` at `severity`.
+fn log_alert(message: &str, severity: Severity) {
+    match severity {
+        Severity::Info => info!(target: LOG_TARGET, "{message}"),
+        Severity::Warning => warn!(target: LOG_TARGET, "{message}"),
+        Severity::Error => error!(target: LOG_TARGET, "{message}"),
+    }
+}
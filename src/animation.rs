@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use bevy_color::Alpha;
+use bevy_ecs::prelude::*;
+use bevy_ui::{BackgroundColor, Style, Val};
+
+/// How an alert appears or disappears, see [`AlertAnimation`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AlertEffect {
+    /// The alert appears or disappears at once.
+    None,
+    /// The background of the alert fades in or out.
+    Fade,
+    /// The alert slides in from or out to the right edge of the window.
+    #[default]
+    SlideFromRight,
+}
+
+/// How an alert is animated when it is shown and when it is dismissed.
+///
+/// Inserted on alert entities from [`AlertTheme::animation`](crate::AlertTheme::animation) when
+/// they are shown, unless they already have one. The [`Ttl`](crate::Ttl) of an alert only starts
+/// counting down once it has entered, and dismissed alerts are despawned once they have exited.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub struct AlertAnimation {
+    /// How the alert appears.
+    pub enter: AlertEffect,
+    /// How the alert disappears.
+    pub exit: AlertEffect,
+    /// How long entering and exiting each take.
+    pub duration: Duration,
+}
+
+impl Default for AlertAnimation {
+    fn default() -> Self {
+        Self {
+            enter: AlertEffect::SlideFromRight,
+            exit: AlertEffect::SlideFromRight,
+            duration: Duration::from_millis(500),
+        }
+    }
+}
+
+impl AlertAnimation {
+    /// Shows `effect` with the alert `visible` between 0 (hidden) and 1 (shown).
+    pub(crate) fn apply(
+        effect: AlertEffect,
+        visible: f32,
+        phase: &AlertPhase,
+        background: &mut BackgroundColor,
+        style: &mut Style,
+    ) {
+        match effect {
+            AlertEffect::None => {}
+            AlertEffect::Fade => {
+                background.0.set_alpha(phase.alpha * visible);
+            }
+            AlertEffect::SlideFromRight => {
+                let hidden = (1. - (std::f32::consts::PI * (1. - visible)).cos()) / 2.;
+                style.left = Val::Percent(hidden * 100.);
+            }
+        }
+    }
+}
+
+/// Where an alert is in its [`AlertAnimation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum AlertStage {
+    Entering,
+    Shown,
+    Exiting,
+}
+
+/// Tracks the [`AlertAnimation`] of a shown alert.
+#[derive(Debug, Component)]
+pub(crate) struct AlertPhase {
+    pub(crate) stage: AlertStage,
+    pub(crate) elapsed: Duration,
+    /// The alpha of the background when the alert is fully shown.
+    pub(crate) alpha: f32,
+}
+
+impl AlertPhase {
+    /// Starts `stage` over.
+    pub(crate) fn start(&mut self, stage: AlertStage) {
+        self.stage = stage;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Starts exiting, returning whether the alert was not already exiting.
+    pub(crate) fn exit(&mut self) -> bool {
+        let exiting = self.stage == AlertStage::Exiting;
+        if !exiting {
+            self.start(AlertStage::Exiting);
+        }
+        !exiting
+    }
+}
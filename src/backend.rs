@@ -0,0 +1,154 @@
+use std::{sync::Arc, time::Duration};
+
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::{error, info, warn};
+
+#[cfg(feature = "ui")]
+use crate::AlertQueue;
+use crate::{
+    AlertAction, AlertL10n, AlertMeta, AlertPayload, AlertReport, AlertReporters, AlertStyle,
+    AlertTag, RaisedAt, Severity, LOG_TARGET,
+};
+
+/// Presents the alerts raised by this crate.
+///
+/// Every alert pipe, [`Alerts`](crate::Alerts) parameter and alert command hands its alerts to
+/// the [`ActiveAlertBackend`] after filtering them and recording them in the
+/// [`AlertHistory`](crate::AlertHistory) and [`ErrorStats`](crate::ErrorStats).
+#[cfg_attr(
+    feature = "ui",
+    doc = "The default, [`UiAlertBackend`], shows them as `bevy_ui_mod_alerts` toasts."
+)]
+#[cfg_attr(
+    not(feature = "ui"),
+    doc = "The default, [`LogAlertBackend`], logs them and sends them as events."
+)]
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// struct ConsoleBackend;
+///
+/// impl AlertBackend for ConsoleBackend {
+///     fn handle(&self, alerts: Vec<RaisedAlert>, _: &mut Commands) {
+///         for alert in alerts {
+///             println!("{:?}: {}", alert.severity, alert.message);
+///         }
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new().with_backend(ConsoleBackend));
+/// ```
+pub trait AlertBackend: Send + Sync + 'static {
+    /// Presents `alerts`, which were raised together.
+    fn handle(&self, alerts: Vec<RaisedAlert>, commands: &mut Commands);
+}
+
+impl std::fmt::Debug for dyn AlertBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("dyn AlertBackend")
+    }
+}
+
+/// An alert raised by this crate, passed to the [`AlertBackend`](crate::AlertBackend).
+///
+#[cfg_attr(
+    feature = "ui",
+    doc = "Waits in the [`AlertQueue`](crate::AlertQueue) of the default backend, and is sent"
+)]
+#[cfg_attr(not(feature = "ui"), doc = "Sent")]
+/// as an event by the [`LogAlertBackend`].
+#[derive(Clone, Debug, Event)]
+pub struct RaisedAlert {
+    /// The message the alert shows, shared with the alerts raised with it recently.
+    pub message: Arc<str>,
+    /// The severity of the alert.
+    pub severity: Severity,
+    /// The number of times the alert was raised; more than 1 while it was queued.
+    pub count: usize,
+    /// When the alert was raised.
+    pub raised_at: RaisedAt,
+    /// How long the alert should stay shown, or `None` if it should not expire.
+    pub ttl: Option<Duration>,
+    /// Whether the alert is [`Sticky`](crate::Sticky).
+    pub sticky: bool,
+    /// The entity the alert was raised for, see [`AlertSource`](crate::AlertSource).
+    pub source: Option<Entity>,
+    /// The style of the alert, see [`AlertStyleRegistry`](crate::AlertStyleRegistry).
+    pub style: Option<AlertStyle>,
+    /// The registered channel the alert was raised to, see [`AlertChannel`](crate::AlertChannel).
+    pub channel: Option<String>,
+    /// The marker components inserted on the alert entity.
+    pub tags: Vec<AlertTag>,
+    /// The error the alert was raised for, see [`ErrorPayload`](crate::ErrorPayload).
+    pub payload: Option<AlertPayload>,
+    /// The support code and help page of the alert.
+    pub meta: Option<AlertMeta>,
+    /// The buttons shown under the message, see [`AlertAction`].
+    pub actions: Vec<AlertAction>,
+    /// Whether the alert is a [`ModalAlert`](crate::ModalAlert).
+    pub modal: bool,
+    /// The [`AlertPriority`](crate::AlertPriority) of the alert.
+    pub priority: i32,
+    /// The localization key of the alert, see [`AlertLocalizer`](crate::AlertLocalizer).
+    pub l10n: Option<AlertL10n>,
+    /// The backtrace of the code that raised the alert.
+    #[cfg(feature = "backtrace")]
+    pub backtrace: crate::Backtrace,
+}
+
+/// The default [`AlertBackend`], which queues alerts in the [`AlertQueue`] to show them as
+/// `bevy_ui_mod_alerts` toasts.
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UiAlertBackend;
+
+#[cfg(feature = "ui")]
+impl AlertBackend for UiAlertBackend {
+    fn handle(&self, alerts: Vec<RaisedAlert>, commands: &mut Commands) {
+        commands.add(move |world: &mut World| AlertQueue::push(world, alerts));
+    }
+}
+
+/// The [`AlertBackend`] used to present alerts, inserted by [`AlertsPlugin`](crate::AlertsPlugin).
+#[derive(Clone, Debug, Resource)]
+pub struct ActiveAlertBackend(pub Arc<dyn AlertBackend>);
+
+/// The [`AlertBackend`] that logs alerts at their severity and sends them as [`RaisedAlert`]
+/// events, the default without the `ui` feature.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogAlertBackend;
+
+impl AlertBackend for LogAlertBackend {
+    fn handle(&self, alerts: Vec<RaisedAlert>, commands: &mut Commands) {
+        for alert in &alerts {
+            let message = &alert.message;
+            match alert.severity {
+                Severity::Info => info!(target: LOG_TARGET, "{message}"),
+                Severity::Warning => warn!(target: LOG_TARGET, "{message}"),
+                Severity::Error => error!(target: LOG_TARGET, "{message}"),
+            }
+        }
+        commands.add(move |world: &mut World| {
+            if let Some(reporters) = world.get_resource::<AlertReporters>() {
+                for alert in &alerts {
+                    reporters.report(AlertReport::raised(alert));
+                }
+            }
+            if let Some(mut events) = world.get_resource_mut::<Events<RaisedAlert>>() {
+                events.send_batch(alerts);
+            }
+        });
+    }
+}
+
+impl Default for ActiveAlertBackend {
+    fn default() -> Self {
+        #[cfg(feature = "ui")]
+        return Self(Arc::new(UiAlertBackend));
+        #[cfg(not(feature = "ui"))]
+        Self(Arc::new(LogAlertBackend))
+    }
+}
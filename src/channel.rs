@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+#[cfg(feature = "ui")]
+use crate::AlertTheme;
+
+/// The channel an alert was raised to, see [`AlertsPlugin::with_channel`].
+///
+/// Inserted on alert entities raised to a registered channel. Alerts raised to other channels
+/// are shown with the default alerts.
+///
+/// [`AlertsPlugin::with_channel`]: crate::AlertsPlugin::with_channel
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Component)]
+pub struct AlertChannel(pub String);
+
+/// Marks the root node of the alerts of a channel, named after the channel.
+#[cfg(feature = "ui")]
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct AlertChannelRoot(pub String);
+
+/// How the alerts of a channel are shown, see [`AlertsPlugin::with_channel`].
+///
+/// [`AlertsPlugin::with_channel`]: crate::AlertsPlugin::with_channel
+#[derive(Clone, Debug)]
+pub struct ChannelConfig {
+    /// The theme of the alerts, including where their stack is anchored.
+    #[cfg(feature = "ui")]
+    pub theme: AlertTheme,
+    /// The maximum number of alerts of the channel shown at once, or `None` for no limit.
+    /// Defaults to 3.
+    pub max_visible: Option<usize>,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "ui")]
+            theme: AlertTheme::default(),
+            max_visible: Some(3),
+        }
+    }
+}
+
+/// The channels registered on the [`AlertsPlugin`](crate::AlertsPlugin).
+#[derive(Debug, Default, Resource)]
+pub(crate) struct AlertChannels(pub(crate) HashMap<String, ChannelConfig>);
+
+#[cfg(feature = "ui")]
+impl AlertChannels {
+    /// The theme of alerts raised to `channel`, or `default` for the default alerts.
+    pub(crate) fn theme<'a>(
+        &'a self,
+        channel: Option<&AlertChannel>,
+        default: &'a AlertTheme,
+    ) -> &'a AlertTheme {
+        channel
+            .and_then(|channel| self.0.get(&channel.0))
+            .map_or(default, |config| &config.theme)
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::info;
+
+use crate::LOG_TARGET;
+
+/// Receives the text of alerts copied with their copy button, see
+/// [`AlertConfig::copy_button`](crate::AlertConfig::copy_button).
+///
+/// The default, [`LogClipboard`], only logs the text. Implement this with the clipboard of your
+/// platform, for example with the `arboard` crate, and register it with
+/// [`AlertsPlugin::with_clipboard`](crate::AlertsPlugin::with_clipboard).
+pub trait AlertClipboard: Send + Sync + 'static {
+    /// Places `text` on the clipboard.
+    fn set_text(&self, text: String);
+}
+
+impl std::fmt::Debug for dyn AlertClipboard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("dyn AlertClipboard")
+    }
+}
+
+/// The default [`AlertClipboard`], which logs the copied text at the info level.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogClipboard;
+
+impl AlertClipboard for LogClipboard {
+    fn set_text(&self, text: String) {
+        info!(target: LOG_TARGET, "copied alert: {text}");
+    }
+}
+
+/// The [`AlertClipboard`] used by the copy buttons, inserted by
+/// [`AlertsPlugin`](crate::AlertsPlugin).
+#[derive(Clone, Debug, Resource)]
+pub(crate) struct ActiveClipboard(pub(crate) Arc<dyn AlertClipboard>);
+
+impl Default for ActiveClipboard {
+    fn default() -> Self {
+        Self(Arc::new(LogClipboard))
+    }
+}
+
+/// The button copying the text of `alert` to the [`AlertClipboard`].
+#[derive(Debug, Component)]
+pub struct CopyButton {
+    pub(crate) alert: Entity,
+}
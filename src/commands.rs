@@ -0,0 +1,309 @@
+use bevy_core::Name;
+use bevy_ecs::{
+    prelude::*,
+    system::{EntityCommands, SystemId, SystemState},
+    world::Command,
+};
+#[cfg(feature = "ui")]
+use bevy_hierarchy::DespawnRecursiveExt;
+#[cfg(feature = "ui")]
+use bevy_ui_mod_alerts::Alert;
+
+#[cfg(feature = "ui")]
+use crate::{AlertDismissed, AlertMessage, AlertQueue, DismissReason, LiveAlerts};
+use crate::{Alerts, ErrorRaised, Severity};
+
+/// A [`Command`] that raises an alert for each of its messages.
+///
+/// Alerts raised this way go through the same [`Alerts`] parameter as the alert pipes, so the
+/// [`AlertConfig`](crate::AlertConfig) settings apply to them too.
+#[derive(Clone, Debug, Default)]
+pub struct RaiseAlerts {
+    /// The message of each alert.
+    pub messages: Vec<String>,
+    /// The severity of every alert.
+    pub severity: Severity,
+}
+
+impl RaiseAlerts {
+    /// Builds a command raising one alert with [`Severity::Error`].
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            messages: vec![message.into()],
+            severity: Severity::Error,
+        }
+    }
+}
+
+impl Command for RaiseAlerts {
+    fn apply(self, world: &mut World) {
+        let mut state = SystemState::<Alerts>::new(world);
+        state.get_mut(world).raise(self.messages, self.severity);
+        state.apply(world);
+    }
+}
+
+/// Dismisses every alert at once, including the queued ones.
+///
+/// Send this as an event to dismiss the alerts before their UI is updated in `PostUpdate`, or
+/// add it as a [`Command`] (see [`AlertCommandsExt::clear_alerts`]) to dismiss them when the
+/// command is applied.
+///
+/// To keep alerts from lingering into another game state, send it when the state is exited,
+/// for example from a system in `OnExit(GameState::InGame)`.
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, Default, Event)]
+pub struct ClearAlerts;
+
+#[cfg(feature = "ui")]
+impl Command for ClearAlerts {
+    fn apply(self, world: &mut World) {
+        dismiss_all(world, DismissReason::Cleared);
+    }
+}
+
+/// Dismisses the alerts when a schedule passed to
+/// [`AlertsPlugin::clear_in`](crate::AlertsPlugin::clear_in) runs.
+#[cfg(feature = "ui")]
+pub(crate) fn clear_on_state_change(world: &mut World) {
+    dismiss_all(world, DismissReason::StateChanged);
+}
+
+/// Despawns every alert and drops the queued ones, forgetting them for deduplication.
+#[cfg(feature = "ui")]
+fn dismiss_all(world: &mut World, reason: DismissReason) {
+    let alerts = world
+        .query_filtered::<(Entity, Option<&AlertMessage>), With<Alert>>()
+        .iter(world)
+        .map(|(entity, message)| (entity, message.map(|message| message.0.clone())))
+        .collect::<Vec<_>>();
+    for (entity, message) in alerts {
+        if let Some(mut dismissed) = world.get_resource_mut::<Events<AlertDismissed>>() {
+            dismissed.send(AlertDismissed {
+                entity,
+                message: message.unwrap_or_default(),
+                reason,
+            });
+        }
+        world.entity_mut(entity).despawn_recursive();
+    }
+    if let Some(mut live) = world.get_resource_mut::<LiveAlerts>() {
+        live.alerts.clear();
+    }
+    if let Some(mut queue) = world.get_resource_mut::<AlertQueue>() {
+        queue.clear();
+    }
+}
+
+/// Defines the `alert`, `alerts` and `clear_alerts` methods which manage alerts from
+/// [`Commands`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn spawn_player(mut commands: Commands, spawn_points: Query<&Transform>) {
+///     if spawn_points.is_empty() {
+///         commands.alert("Couldn't find spawn point");
+///     }
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Update, spawn_player);
+/// ```
+pub trait AlertCommandsExt {
+    /// Raises an alert showing `message`.
+    fn alert(&mut self, message: impl Into<String>);
+
+    /// Raises an alert for each message.
+    fn alerts(&mut self, messages: impl IntoIterator<Item = String>);
+
+    /// Dismisses every alert. See [`ClearAlerts`].
+    #[cfg(feature = "ui")]
+    fn clear_alerts(&mut self);
+}
+
+impl AlertCommandsExt for Commands<'_, '_> {
+    fn alert(&mut self, message: impl Into<String>) {
+        self.add(RaiseAlerts::new(message));
+    }
+
+    fn alerts(&mut self, messages: impl IntoIterator<Item = String>) {
+        self.add(RaiseAlerts {
+            messages: messages.into_iter().collect(),
+            severity: Severity::Error,
+        });
+    }
+
+    #[cfg(feature = "ui")]
+    fn clear_alerts(&mut self) {
+        self.add(ClearAlerts);
+    }
+}
+
+/// Defines the `alert`, `alert_with` and `alert_error` methods which raise alerts from exclusive
+/// systems and [`Command`]s.
+///
+/// The alerts are raised right away, through the same [`Alerts`] parameter as the alert pipes,
+/// and shown in the next `PostUpdate`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn reload_scene(world: &mut World) {
+///     if !world.contains_resource::<Time>() {
+///         world.alert_with(Severity::Warning, "Reloading the scene without a clock");
+///     }
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Update, reload_scene);
+/// ```
+pub trait WorldAlertExt {
+    /// Raises an alert showing `message`.
+    fn alert(&mut self, message: impl Into<String>);
+
+    /// Raises an alert with the given [`Severity`] showing `message`.
+    fn alert_with(&mut self, severity: Severity, message: impl Into<String>);
+
+    /// Raises an alert showing the `Display` output of `error`.
+    fn alert_error(&mut self, error: &impl std::fmt::Display);
+}
+
+impl WorldAlertExt for World {
+    fn alert(&mut self, message: impl Into<String>) {
+        self.alert_with(Severity::Error, message);
+    }
+
+    fn alert_with(&mut self, severity: Severity, message: impl Into<String>) {
+        RaiseAlerts {
+            messages: vec![message.into()],
+            severity,
+        }
+        .apply(self);
+    }
+
+    fn alert_error(&mut self, error: &impl std::fmt::Display) {
+        self.alert(error.to_string());
+    }
+}
+
+/// Defines the `run_system_alert` and `run_system_with_input_alert` methods which run one-shot
+/// systems returning a `Result`, raising an alert if they fail.
+///
+/// Errors are raised like those of [`AnyhowAlertExt::anyhow_alert`](crate::AnyhowAlertExt),
+/// and a system that can't be run (for example because it was removed) raises an alert too.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn save_game() -> anyhow::Result<()> { /* ... */ Ok(()) }
+///
+/// #[derive(Resource)]
+/// struct SaveGame(bevy::ecs::system::SystemId<(), anyhow::Result<()>>);
+///
+/// fn on_save_pressed(mut commands: Commands, save: Res<SaveGame>) {
+///     commands.run_system_alert(save.0);
+/// }
+/// # let mut app = App::new();
+/// # let id = app.world_mut().register_system(save_game);
+/// # app.insert_resource(SaveGame(id));
+/// # app.add_systems(Update, on_save_pressed);
+/// ```
+pub trait RunSystemAlertExt {
+    /// Runs the system `id`, raising an alert if it returns an error.
+    fn run_system_alert<E>(&mut self, id: SystemId<(), Result<(), E>>)
+    where
+        E: std::fmt::Display + Send + Sync + 'static,
+    {
+        self.run_system_with_input_alert(id, ());
+    }
+
+    /// Runs the system `id` with `input`, raising an alert if it returns an error.
+    fn run_system_with_input_alert<I, E>(&mut self, id: SystemId<I, Result<(), E>>, input: I)
+    where
+        I: Send + 'static,
+        E: std::fmt::Display + Send + Sync + 'static;
+}
+
+impl RunSystemAlertExt for World {
+    fn run_system_with_input_alert<I, E>(&mut self, id: SystemId<I, Result<(), E>>, input: I)
+    where
+        I: Send + 'static,
+        E: std::fmt::Display + Send + Sync + 'static,
+    {
+        match self.run_system_with_input(id, input) {
+            Ok(Ok(())) => {}
+            Ok(result) => {
+                let mut state = SystemState::<Alerts>::new(self);
+                crate::anyhow_alert_system::<(), (), E>(In(result), state.get_mut(self));
+                state.apply(self);
+            }
+            Err(error) => RaiseAlerts::new(error.to_string()).apply(self),
+        }
+    }
+}
+
+impl RunSystemAlertExt for Commands<'_, '_> {
+    fn run_system_with_input_alert<I, E>(&mut self, id: SystemId<I, Result<(), E>>, input: I)
+    where
+        I: Send + 'static,
+        E: std::fmt::Display + Send + Sync + 'static,
+    {
+        self.add(move |world: &mut World| world.run_system_with_input_alert(id, input));
+    }
+}
+
+/// Defines the `alert` and `trigger_alert` methods which raise alerts for an entity.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn check_health(mut commands: Commands, players: Query<(Entity, &Name)>) {
+///     for (player, name) in &players {
+///         commands.entity(player).trigger_alert(format!("{name} has no health bar"));
+///     }
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Update, check_health);
+/// ```
+pub trait AlertEntityCommandsExt {
+    /// Raises an alert showing `error` for this entity, recording it as the
+    /// [`AlertSource`](crate::AlertSource) of the alert.
+    ///
+    /// The [`Name`] of the entity is appended to the message if it has one, as in
+    /// `"no pathfinding agent (NPC 42)"`.
+    fn alert(&mut self, error: impl std::fmt::Display) -> &mut Self;
+
+    /// Triggers an [`ErrorRaised`] for this entity, recording it as the
+    /// [`AlertSource`](crate::AlertSource) of the alert.
+    fn trigger_alert(&mut self, message: impl std::fmt::Display) -> &mut Self;
+}
+
+impl AlertEntityCommandsExt for EntityCommands<'_> {
+    fn alert(&mut self, error: impl std::fmt::Display) -> &mut Self {
+        let message = error.to_string();
+        self.add(move |entity: Entity, world: &mut World| {
+            let message = match world.get::<Name>(entity) {
+                Some(name) => format!("{message} ({name})"),
+                None => message,
+            };
+            let mut state = SystemState::<Alerts>::new(world);
+            state
+                .get_mut(world)
+                .push_from(message, Severity::Error, entity);
+            state.apply(world);
+        })
+    }
+
+    fn trigger_alert(&mut self, message: impl std::fmt::Display) -> &mut Self {
+        let entity = self.id();
+        let event = ErrorRaised {
+            source: Some(entity),
+            ..ErrorRaised::new(message.to_string())
+        };
+        self.commands().trigger_targets(event, entity);
+        self
+    }
+}
@@ -0,0 +1,156 @@
+use std::{
+    collections::HashMap,
+    io::{IsTerminal, Write},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy_ecs::prelude::*;
+
+use crate::{AlertBackend, AlertConfig, RaisedAlert, Severity};
+
+/// An [`AlertBackend`] printing alerts to the terminal as boxed blocks, for dedicated servers
+/// and other builds without a UI.
+///
+/// ```text
+/// +- ERROR ---+
+/// | disk full |
+/// +-----------+
+/// ```
+///
+/// The borders are colored by severity with ANSI codes, unless the output isn't a terminal.
+/// When [`AlertConfig::dedup`] is enabled, a message raised again before the [`Ttl`](crate::Ttl)
+/// of its last block runs out isn't printed again; messages without a TTL are printed once.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new().with_backend(ConsoleAlertBackend::new()));
+/// ```
+#[derive(Clone)]
+pub struct ConsoleAlertBackend {
+    out: Arc<Mutex<Box<dyn Write + Send>>>,
+    ansi: bool,
+    /// When each printed message stops being folded into its block.
+    printed: Arc<Mutex<HashMap<ConsoleKey, Duration>>>,
+}
+
+type ConsoleKey = (Severity, Arc<str>, Option<String>);
+
+impl ConsoleAlertBackend {
+    /// Prints alerts to stderr, with colors if it is a terminal.
+    pub fn new() -> Self {
+        let ansi = std::io::stderr().is_terminal();
+        Self::to_writer(std::io::stderr()).with_ansi(ansi)
+    }
+
+    /// Prints alerts to `out`, without colors.
+    pub fn to_writer(out: impl Write + Send + 'static) -> Self {
+        Self {
+            out: Arc::new(Mutex::new(Box::new(out))),
+            ansi: false,
+            printed: Arc::default(),
+        }
+    }
+
+    /// Colors the borders with ANSI codes when `ansi` is `true`.
+    pub fn with_ansi(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+        self
+    }
+
+    /// Whether `alert` is folded into a block printed earlier, remembering it otherwise.
+    fn folded(&self, alert: &RaisedAlert) -> bool {
+        let now = alert.raised_at.real_time;
+        let key = (alert.severity, alert.message.clone(), alert.channel.clone());
+        let mut printed = self.printed.lock().unwrap();
+        printed.retain(|_, until| *until > now);
+        if printed.contains_key(&key) {
+            return true;
+        }
+        let until = alert
+            .ttl
+            .map_or(Duration::MAX, |ttl| now.saturating_add(ttl));
+        printed.insert(key, until);
+        false
+    }
+
+    /// Renders `alert` as a bordered block.
+    fn render(&self, alert: &RaisedAlert) -> String {
+        let label = match alert.severity {
+            Severity::Info => "INFO",
+            Severity::Warning => "WARNING",
+            Severity::Error => "ERROR",
+        };
+        let mut lines: Vec<String> = alert.message.lines().map(str::to_owned).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        if alert.count > 1 {
+            let last = lines.last_mut().unwrap();
+            last.push_str(&format!(" (x{})", alert.count));
+        }
+        let width = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(label.len() + 2);
+        let (color, reset) = match (self.ansi, alert.severity) {
+            (false, _) => ("", ""),
+            (true, Severity::Info) => ("\x1b[36m", "\x1b[0m"),
+            (true, Severity::Warning) => ("\x1b[33m", "\x1b[0m"),
+            (true, Severity::Error) => ("\x1b[31m", "\x1b[0m"),
+        };
+        let mut block = format!(
+            "{color}+- {label} {}+{reset}\n",
+            "-".repeat(width - label.len() - 1)
+        );
+        for line in &lines {
+            let padding = " ".repeat(width - line.chars().count());
+            block.push_str(&format!(
+                "{color}|{reset} {line}{padding} {color}|{reset}\n"
+            ));
+        }
+        block.push_str(&format!("{color}+{}+{reset}\n", "-".repeat(width + 2)));
+        block
+    }
+}
+
+impl Default for ConsoleAlertBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ConsoleAlertBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsoleAlertBackend")
+            .field("ansi", &self.ansi)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AlertBackend for ConsoleAlertBackend {
+    fn handle(&self, alerts: Vec<RaisedAlert>, commands: &mut Commands) {
+        let console = self.clone();
+        commands.add(move |world: &mut World| {
+            let dedup = world
+                .get_resource::<AlertConfig>()
+                .map_or(true, |config| config.dedup);
+            let blocks: String = alerts
+                .iter()
+                .filter(|alert| !(dedup && console.folded(alert)))
+                .map(|alert| console.render(alert))
+                .collect();
+            if blocks.is_empty() {
+                return;
+            }
+            let mut out = console.out.lock().unwrap();
+            // like log output, alerts that can't be printed are dropped
+            let _ = out.write_all(blocks.as_bytes()).and_then(|()| out.flush());
+        });
+    }
+}
@@ -0,0 +1,67 @@
+use bevy_app::prelude::*;
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use bevy_ui_mod_alerts::Alert;
+
+use crate::ErrorStats;
+
+/// Adds diagnostics measuring the alerts raised by this crate, such as for
+/// `LogDiagnosticsPlugin` or soak tests.
+///
+/// Requires the `diagnostics` feature and the [`AlertsPlugin`](crate::AlertsPlugin).
+///
+/// ```
+/// use bevy::{diagnostic::DiagnosticsPlugin, prelude::*};
+/// use bevy_anyhow_alert::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((DiagnosticsPlugin, AlertsPlugin::new(), AlertDiagnosticsPlugin));
+/// ```
+#[derive(Debug, Default)]
+pub struct AlertDiagnosticsPlugin;
+
+impl AlertDiagnosticsPlugin {
+    /// The number of messages raised since the app started, counting repeated ones.
+    pub const RAISED_TOTAL: DiagnosticPath = DiagnosticPath::const_new("anyhow_alert/raised_total");
+    /// The number of alert entities.
+    pub const ACTIVE: DiagnosticPath = DiagnosticPath::const_new("anyhow_alert/active");
+    /// The number of messages raised per second.
+    pub const RAISED_PER_SECOND: DiagnosticPath =
+        DiagnosticPath::const_new("anyhow_alert/raised_per_second");
+
+    /// Measures the diagnostics of this plugin.
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        stats: Res<ErrorStats>,
+        alerts: Query<(), With<Alert>>,
+        time: Res<Time<Real>>,
+        // the total of the stats when last measured, and the total since the app started
+        mut totals: Local<(usize, usize)>,
+    ) {
+        let (last_total, raised_total) = &mut *totals;
+        // the stats start over when they are reset
+        let raised = stats
+            .total()
+            .checked_sub(*last_total)
+            .unwrap_or(stats.total());
+        *last_total = stats.total();
+        *raised_total += raised;
+        diagnostics.add_measurement(&Self::RAISED_TOTAL, || *raised_total as f64);
+        diagnostics.add_measurement(&Self::ACTIVE, || alerts.iter().count() as f64);
+        let delta_seconds = time.delta_seconds_f64();
+        if delta_seconds > 0. {
+            diagnostics.add_measurement(&Self::RAISED_PER_SECOND, || raised as f64 / delta_seconds);
+        }
+    }
+}
+
+impl Plugin for AlertDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::RAISED_TOTAL))
+            .register_diagnostic(Diagnostic::new(Self::ACTIVE))
+            .register_diagnostic(Diagnostic::new(Self::RAISED_PER_SECOND))
+            // alerts are spawned in `PostUpdate`
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
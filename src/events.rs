@@ -0,0 +1,185 @@
+use bevy_ecs::{
+    event::{EventRegistry, Events},
+    prelude::*,
+    system::SystemParam,
+};
+
+use crate::{Alerts, RaiseOptions, Severity};
+
+/// An event carrying an error returned by a system piped through
+/// [`AnyhowAlertExt::anyhow_emit`] or [`AnyhowAlertExt::anyhow_alert_and_emit`].
+///
+/// The event type is registered the first time an error is emitted, or up front with
+/// [`AlertsPlugin::with_error_events`].
+///
+/// [`AnyhowAlertExt::anyhow_emit`]: crate::AnyhowAlertExt::anyhow_emit
+/// [`AnyhowAlertExt::anyhow_alert_and_emit`]: crate::AnyhowAlertExt::anyhow_alert_and_emit
+/// [`AlertsPlugin::with_error_events`]: crate::AlertsPlugin::with_error_events
+#[derive(Debug, Event)]
+pub struct ErrorEvent<E: Send + Sync + 'static>(pub E);
+
+/// An event that raises an alert when triggered, for code without a system to return errors
+/// from, such as observers and component hooks.
+///
+/// [`AlertsPlugin`](crate::AlertsPlugin) observes this event. When it is triggered for an
+/// entity and has no `source`, the target entity is recorded as the [`AlertSource`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn check_spawned(trigger: Trigger<OnAdd, Transform>, mut commands: Commands) {
+///     commands.trigger(ErrorRaised::new("spawned outside the map"));
+/// }
+/// # let mut app = App::new();
+/// # app.observe(check_spawned);
+/// ```
+///
+/// [`AlertSource`]: crate::AlertSource
+#[derive(Clone, Debug, Event)]
+pub struct ErrorRaised {
+    /// The message of the alert.
+    pub message: String,
+    /// The severity of the alert.
+    pub severity: Severity,
+    /// The entity the alert was raised for.
+    pub source: Option<Entity>,
+}
+
+impl ErrorRaised {
+    /// Builds an event raising an alert with [`Severity::Error`] and no source.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            severity: Severity::Error,
+            source: None,
+        }
+    }
+
+    /// Raises an alert for each triggered [`ErrorRaised`].
+    pub(crate) fn observe(trigger: Trigger<Self>, mut alerts: Alerts) {
+        let event = trigger.event();
+        let target = trigger.entity();
+        let source = event
+            .source
+            .or((target != Entity::PLACEHOLDER).then_some(target));
+        let options = RaiseOptions {
+            source,
+            ..Default::default()
+        };
+        alerts.raise_with([event.message.clone()], event.severity, options);
+    }
+}
+
+/// Sent and triggered for the alert entity when an alert is spawned.
+///
+/// Every alert raised by this crate is spawned from the `AlertQueue`, so this covers alerts
+/// raised by the pipes, [`Alerts`], and [`AlertCommandsExt`] alike. Observe it to react to new
+/// alerts right away, or read it as an event.
+#[cfg_attr(
+    not(feature = "ui"),
+    doc = "\nOnly sent with the `ui` feature, which spawns the alert entities."
+)]
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new());
+/// app.observe(|trigger: Trigger<AlertRaised>| {
+///     if trigger.event().severity == Severity::Error {
+///         // shake the screen
+///     }
+/// });
+/// ```
+///
+/// [`AlertCommandsExt`]: crate::AlertCommandsExt
+#[derive(Clone, Debug, PartialEq, Eq, Event)]
+pub struct AlertRaised {
+    /// The alert entity.
+    pub entity: Entity,
+    /// The message of the alert.
+    pub message: String,
+    /// The severity of the alert.
+    pub severity: Severity,
+}
+
+/// Sent and triggered for the alert entity when a repeated message is folded into a shown
+/// alert by [`AlertConfig::dedup`](crate::AlertConfig::dedup), instead of [`AlertRaised`].
+///
+/// Messages folded into queued alerts are counted once the alert is spawned.
+#[cfg_attr(
+    not(feature = "ui"),
+    doc = "\nOnly sent with the `ui` feature, which spawns the alert entities."
+)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Event)]
+pub struct AlertRepeated {
+    /// The alert entity.
+    pub entity: Entity,
+    /// The new [`AlertCount`](crate::AlertCount) of the alert.
+    pub count: usize,
+}
+
+/// Sent when an alert starts to be dismissed, before its exit `AlertAnimation` plays.
+///
+/// Alerts dismissed by `ClearAlerts` are despawned right after the event is sent, so readers
+/// may no longer find the entity; the event carries the message for them.
+#[cfg_attr(
+    not(feature = "ui"),
+    doc = "\nOnly sent with the `ui` feature, which spawns the alert entities."
+)]
+#[derive(Clone, Debug, PartialEq, Eq, Event)]
+pub struct AlertDismissed {
+    /// The alert entity, which is despawned once it has exited.
+    pub entity: Entity,
+    /// The message of the alert, see [`AlertMessage`](crate::AlertMessage).
+    pub message: String,
+    /// Why the alert was dismissed.
+    pub reason: DismissReason,
+}
+
+/// Why an alert was dismissed, see [`AlertDismissed`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DismissReason {
+    /// The [`Ttl`](crate::Ttl) of the alert ran out.
+    Expired,
+    /// The user dismissed the alert with its dismiss button, a click, or a hotkey.
+    User,
+    /// `ClearAlerts` dismissed every alert.
+    Cleared,
+    /// A higher [`AlertPriority`](crate::AlertPriority) alert took the place of the alert while
+    /// `AlertConfig::max_visible` alerts were shown.
+    Evicted,
+    /// The alerts were cleared on a state change, see `AlertsPlugin::clear_in`.
+    StateChanged,
+}
+
+/// A [`SystemParam`] that writes [`ErrorEvent`]s, registering the event type if needed.
+#[derive(SystemParam)]
+pub(crate) struct ErrorEvents<'w, 's, E: Send + Sync + 'static> {
+    commands: Commands<'w, 's>,
+    events: Option<ResMut<'w, Events<ErrorEvent<E>>>>,
+}
+
+impl<E: Send + Sync + 'static> ErrorEvents<'_, '_, E> {
+    /// Writes an [`ErrorEvent`] carrying `error`.
+    pub(crate) fn send(&mut self, error: E) {
+        match self.events.as_mut() {
+            Some(events) => {
+                events.send(ErrorEvent(error));
+            }
+            None => self.commands.add(move |world: &mut World| {
+                register_error_events::<E>(world);
+                world.send_event(ErrorEvent(error));
+            }),
+        }
+    }
+}
+
+/// Registers [`ErrorEvent<E>`] in `world` if it is not registered yet.
+pub(crate) fn register_error_events<E: Send + Sync + 'static>(world: &mut World) {
+    if !world.contains_resource::<Events<ErrorEvent<E>>>() {
+        EventRegistry::register_event::<ErrorEvent<E>>(world);
+    }
+}
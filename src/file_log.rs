@@ -0,0 +1,219 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, Sender},
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_utils::tracing::warn;
+
+use crate::{RaisedAt, Severity, LOG_TARGET};
+
+/// Appends every raised alert to a file, see
+/// [`AlertsPlugin::log_to_file`](crate::AlertsPlugin::log_to_file).
+///
+/// Each alert is written as one line with its timestamp, severity, count, and message:
+///
+/// ```text
+/// [00:03:45] Error x2 loading settings: disk full
+/// ```
+///
+/// The count is the number of times the message was raised in a row, like
+/// [`AlertHistoryEntry::count`](crate::AlertHistoryEntry::count). Lines are written by a
+/// background thread, so a slow disk doesn't hold up the frame. Once the file would grow past
+/// [`FileAlertSink::max_size`], it is renamed to the same path with a `.1` extension appended,
+/// replacing the previous one, and a new file is started.
+#[derive(Clone, Debug, Resource)]
+pub struct FileAlertSink {
+    path: PathBuf,
+    max_size: u64,
+    sender: Sender<SinkMessage>,
+}
+
+#[derive(Debug)]
+enum SinkMessage {
+    Line(String),
+    Flush(Sender<()>),
+}
+
+impl FileAlertSink {
+    /// The name of the file in [`FileAlertSink::next_to_executable`].
+    pub const DEFAULT_FILE_NAME: &'static str = "errors.log";
+
+    /// Starts appending alerts to the file at `path`, rotating it once it would grow past
+    /// `max_size` bytes.
+    pub fn new(path: impl Into<PathBuf>, max_size: u64) -> Self {
+        let path = path.into();
+        let (sender, receiver) = mpsc::channel();
+        let writer = SinkWriter {
+            path: path.clone(),
+            max_size,
+            file: None,
+            size: 0,
+        };
+        std::thread::Builder::new()
+            .name("alert file sink".into())
+            .spawn(move || writer.run(receiver))
+            .expect("failed to spawn the alert file sink thread");
+        Self {
+            path,
+            max_size,
+            sender,
+        }
+    }
+
+    /// The path of [`FileAlertSink::DEFAULT_FILE_NAME`] in the directory of the executable, or
+    /// the working directory if it isn't known.
+    pub fn next_to_executable() -> PathBuf {
+        std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+            .unwrap_or_default()
+            .join(Self::DEFAULT_FILE_NAME)
+    }
+
+    /// The file alerts are appended to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The size in bytes the file is rotated at.
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    /// Queues the line of an alert.
+    pub(crate) fn append(&self, message: &str, severity: Severity, at: RaisedAt, count: usize) {
+        let message = message.replace('\n', " ");
+        let line = format!("{} {severity:?} x{count} {message}\n", at.timestamp());
+        // the thread only stops once every sink is dropped
+        let _ = self.sender.send(SinkMessage::Line(line));
+    }
+
+    /// Blocks until every queued alert has been written to the file.
+    ///
+    /// Called when the app exits, so the last alerts aren't lost.
+    pub fn flush(&self) {
+        let (done, wait) = mpsc::channel();
+        if self.sender.send(SinkMessage::Flush(done)).is_ok() {
+            let _ = wait.recv();
+        }
+    }
+}
+
+struct SinkWriter {
+    path: PathBuf,
+    max_size: u64,
+    file: Option<BufWriter<File>>,
+    size: u64,
+}
+
+impl SinkWriter {
+    fn run(mut self, receiver: Receiver<SinkMessage>) {
+        while let Ok(message) = receiver.recv() {
+            self.handle(message);
+            // write everything queued at once, but don't keep lines buffered while idle
+            for message in receiver.try_iter() {
+                self.handle(message);
+            }
+            self.flush();
+        }
+    }
+
+    fn handle(&mut self, message: SinkMessage) {
+        match message {
+            SinkMessage::Line(line) => self.write(&line),
+            SinkMessage::Flush(done) => {
+                self.flush();
+                let _ = done.send(());
+            }
+        }
+    }
+
+    fn write(&mut self, line: &str) {
+        let len = line.len() as u64;
+        if self.file.is_none() {
+            self.open();
+        }
+        if self.size > 0 && self.size + len > self.max_size {
+            self.rotate();
+        }
+        let Some(file) = self.file.as_mut() else {
+            return;
+        };
+        match file.write_all(line.as_bytes()) {
+            Ok(()) => self.size += len,
+            Err(error) => warn!(
+                target: LOG_TARGET,
+                "couldn't write to the alert log {}: {error}",
+                self.path.display()
+            ),
+        }
+    }
+
+    /// Renames the file to the rotated path and starts a new one.
+    fn rotate(&mut self) {
+        self.flush();
+        self.file = None;
+        let mut rotated = self.path.clone().into_os_string();
+        rotated.push(".1");
+        if let Err(error) = std::fs::rename(&self.path, &rotated) {
+            warn!(
+                target: LOG_TARGET,
+                "couldn't rotate the alert log {}: {error}",
+                self.path.display()
+            );
+        }
+        self.open();
+    }
+
+    fn open(&mut self) {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path);
+        match file.and_then(|file| Ok((file.metadata()?.len(), file))) {
+            Ok((size, file)) => {
+                self.size = size;
+                self.file = Some(BufWriter::new(file));
+            }
+            Err(error) => warn!(
+                target: LOG_TARGET,
+                "couldn't open the alert log {}: {error}",
+                self.path.display()
+            ),
+        }
+    }
+
+    fn flush(&mut self) {
+        if let Some(file) = self.file.as_mut() {
+            if let Err(error) = file.flush() {
+                warn!(
+                    target: LOG_TARGET,
+                    "couldn't write to the alert log {}: {error}",
+                    self.path.display()
+                );
+            }
+        }
+    }
+}
+
+pub(crate) fn log_to_file(app: &mut App, path: PathBuf, max_size: u64) {
+    if cfg!(target_arch = "wasm32") {
+        warn!(
+            target: LOG_TARGET,
+            "alerts can't be logged to a file on the web"
+        );
+        return;
+    }
+    app.insert_resource(FileAlertSink::new(path, max_size))
+        .add_systems(Last, flush_on_exit);
+}
+
+fn flush_on_exit(sink: Res<FileAlertSink>, mut exits: EventReader<AppExit>) {
+    if exits.read().next().is_some() {
+        sink.flush();
+    }
+}
@@ -0,0 +1,221 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy_ecs::prelude::*;
+
+use crate::{AlertMeta, RaisedAt, Severity};
+
+/// A [`Command`](bevy_ecs::world::Command) that writes the [`AlertHistory`] to `path` with [`AlertHistory::to_ron`], to
+/// attach it to bug reports.
+///
+/// Failing to write the file is logged as a warning. Does nothing but warn on the web, where
+/// there is no file system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn dump_on_exit(mut commands: Commands, mut exits: EventReader<AppExit>) {
+///     if exits.read().next().is_some() {
+///         commands.add(DumpAlertHistory {
+///             path: "alerts.ron".into(),
+///         });
+///     }
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Last, dump_on_exit);
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug)]
+pub struct DumpAlertHistory {
+    /// The file the history is written to.
+    pub path: std::path::PathBuf,
+}
+
+#[cfg(feature = "serde")]
+impl bevy_ecs::world::Command for DumpAlertHistory {
+    fn apply(self, world: &mut World) {
+        if cfg!(target_arch = "wasm32") {
+            bevy_utils::tracing::warn!(
+                target: crate::LOG_TARGET,
+                "the alert history can't be written to a file on the web"
+            );
+            return;
+        }
+        let Some(history) = world.get_resource::<AlertHistory>() else {
+            return;
+        };
+        if let Err(error) = std::fs::write(&self.path, history.to_ron()) {
+            bevy_utils::tracing::warn!(
+                target: crate::LOG_TARGET,
+                "couldn't write the alert history to {}: {error}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// A bounded log of the alerts raised by this crate, oldest first.
+///
+/// Inserted by [`AlertsPlugin`](crate::AlertsPlugin) with a capacity of
+/// [`AlertHistory::DEFAULT_CAPACITY`]. Insert [`AlertHistory::with_capacity`] to keep more or
+/// fewer entries; once full, the oldest entry is evicted for each new one.
+#[derive(Clone, Debug, Resource)]
+pub struct AlertHistory {
+    entries: VecDeque<AlertHistoryEntry>,
+    capacity: usize,
+    /// The number of entries recorded in this run, including evicted and cleared ones.
+    recorded: u64,
+}
+
+/// An alert recorded in the [`AlertHistory`].
+///
+/// With the `serde` feature, the entry can be serialized without its backtrace; see
+/// `AlertHistory::to_ron`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlertHistoryEntry {
+    /// The message shown by the alert.
+    pub message: String,
+    /// The severity of the alert.
+    pub severity: Severity,
+    /// The [`Time<Real>`](bevy_time::Real) elapsed when the alert was first raised.
+    pub raised_at: Duration,
+    /// The [`FrameCount`](bevy_core::FrameCount) when the alert was first raised.
+    pub frame: u32,
+    /// The [`Time<Real>`](bevy_time::Real) elapsed when the alert was last raised.
+    pub last_seen: Duration,
+    /// The number of times the alert was raised in a row.
+    pub count: usize,
+    /// The support code and help page of the alert, see [`AlertMeta`].
+    pub meta: Option<AlertMeta>,
+    /// Whether the alert was raised in a previous run of the app, loaded by
+    /// `AlertsPlugin::persist_history` with the `serde` feature.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub previous_session: bool,
+    /// The backtrace of the code that first raised the alert.
+    #[cfg(feature = "backtrace")]
+    #[cfg_attr(feature = "serde", serde(skip, default = "crate::Backtrace::disabled"))]
+    pub backtrace: crate::Backtrace,
+}
+
+impl Default for AlertHistory {
+    fn default() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl AlertHistory {
+    /// The number of entries kept by default.
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// Builds an empty history keeping at most `capacity` entries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            recorded: 0,
+        }
+    }
+
+    /// The maximum number of entries kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Iterates over every entry, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &AlertHistoryEntry> + '_ {
+        self.entries.iter()
+    }
+
+    /// Iterates over the `n` most recent entries, oldest first.
+    pub fn latest(&self, n: usize) -> impl DoubleEndedIterator<Item = &AlertHistoryEntry> + '_ {
+        self.entries.range(self.entries.len().saturating_sub(n)..)
+    }
+
+    /// The number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Serializes every entry, oldest first, as a RON list.
+    #[cfg(feature = "serde")]
+    pub fn to_ron(&self) -> String {
+        ron::ser::to_string_pretty(&self.entries, ron::ser::PrettyConfig::default())
+            .expect("the entries of the alert history are always serializable")
+    }
+
+    /// Parses the entries written by [`AlertHistory::to_ron`].
+    #[cfg(feature = "serde")]
+    pub fn entries_from_ron(ron: &str) -> Result<Vec<AlertHistoryEntry>, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// The number of entries recorded in this run so far, not counting restored ones.
+    #[cfg(feature = "serde")]
+    pub(crate) fn recorded(&self) -> u64 {
+        self.recorded
+    }
+
+    /// Inserts `entries` from a previous session before the current entries, keeping the most
+    /// recent ones if there are more than the capacity.
+    #[cfg(feature = "serde")]
+    pub(crate) fn restore(&mut self, entries: Vec<AlertHistoryEntry>) {
+        let current = std::mem::take(&mut self.entries);
+        let previous = entries.into_iter().map(|entry| AlertHistoryEntry {
+            previous_session: true,
+            ..entry
+        });
+        self.entries = previous.chain(current).collect();
+        let excess = self.entries.len().saturating_sub(self.capacity);
+        self.entries.drain(..excess);
+    }
+
+    /// Records an alert, folding it into the latest entry if that entry is the same alert.
+    pub(crate) fn record(
+        &mut self,
+        message: &str,
+        severity: Severity,
+        raised_at: RaisedAt,
+        meta: Option<&AlertMeta>,
+        #[cfg(feature = "backtrace")] backtrace: &crate::Backtrace,
+    ) {
+        if let Some(latest) = self.entries.back_mut() {
+            let same = latest.severity == severity && latest.message == message;
+            // alerts of this run aren't folded into the entries loaded from the previous one
+            if same && !latest.previous_session {
+                latest.count += 1;
+                latest.last_seen = raised_at.last_seen;
+                return;
+            }
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        self.recorded += 1;
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(AlertHistoryEntry {
+            message: message.to_string(),
+            severity,
+            raised_at: raised_at.real_time,
+            frame: raised_at.frame,
+            last_seen: raised_at.last_seen,
+            count: 1,
+            meta: meta.cloned(),
+            previous_session: false,
+            #[cfg(feature = "backtrace")]
+            backtrace: backtrace.clone(),
+        });
+    }
+}
@@ -38,29 +38,70 @@
 //! }
 //! ```
 //!
+//! The `Ok` value can be kept by piping into a system that accepts `In<Option<T>>`:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_anyhow_alert::{AnyhowAlertExt, anyhow::Result};
+//!
+//! struct Config;
+//!
+//! fn load_config() -> Result<Config> {
+//!     Ok(Config)
+//! }
+//!
+//! fn apply_config(In(config): In<Option<Config>>) {
+//!     // `config` is `None` if loading failed and an alert was raised
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_systems(Update, load_config.anyhow_alert().pipe(apply_config));
+//! ```
+//!
+//! Systems taking `In<T>` can be alerted either before or after being piped into:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_anyhow_alert::{AnyhowAlertExt, anyhow::{ensure, Result}};
+//!
+//! fn read_level() -> u32 {
+//!     3
+//! }
+//!
+//! fn check_level(In(level): In<u32>) -> Result<()> {
+//!     ensure!(level < 10, "level {level} doesn't exist");
+//!     Ok(())
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_systems(Update, read_level.pipe(check_level).anyhow_alert());
+//! app.add_systems(Update, read_level.pipe(check_level.anyhow_alert()));
+//! ```
+//!
 //! Alternatively, the system can collect errors without interrupting the iteration and return
-//! a vector of `Result`s:
+//! them all, raising an alert for each one:
 //!
 //! ```
 //! use bevy::prelude::*;
-//! use bevy_anyhow_alert::{AnyhowAlertExt, ResultVec};
+//! use bevy_anyhow_alert::{AlertAllExt, AlertsPlugin};
 //! use bevy_anyhow_alert::anyhow::{Error, Result};
 //!
+//! fn main() {
+//!     let mut app = App::new();
+//!     app.add_plugins(MinimalPlugins);
+//!     app.add_plugins(AlertsPlugin::new());
+//!     app.add_systems(Update, fallible_system.alert_all());
+//!     // app.run();
+//! }
+//!
 //! #[derive(Component)]
 //! struct MyComponent;
 //!
-//! fn fallible_system(my_query: Query<&MyComponent>) -> ResultVec<(), Error> {
-//!     let mut errors = vec![];
-//!     for my_value in my_query.iter() {
-//!         if let Err(error) = get_result() {
-//!            errors.push(error);
-//!         }
-//!     }
-//!     if errors.is_empty() {
-//!         Ok(())
-//!     } else {
-//!         Err(errors)
-//!     }
+//! fn fallible_system(my_query: Query<&MyComponent>) -> Vec<Error> {
+//!     my_query
+//!         .iter()
+//!         .filter_map(|my_value| get_result().err())
+//!         .collect()
 //! }
 //!
 //! fn get_result() -> Result<()> {
@@ -68,174 +109,5614 @@
 //! }
 //! ```
 //!
-//! The resulting UI is somewhat restylable but may not fit every application.
+//! Systems returning a [`ResultVec`] of errors can use [`AnyhowAlertsExt::anyhow_alerts`]
+//! instead, which also forwards the `Ok` value.
+//!
+//! When a system keeps returning the same error, the message is only shown once along with an
+//! occurrence counter. See [`AlertConfig`] to turn this off.
+//!
+#![cfg_attr(
+    feature = "ui",
+    doc = "The resulting UI can be restyled with [`AlertTheme`] but may not fit every \
+           application; see [`AlertBackend`] to present alerts some other way."
+)]
+#![cfg_attr(
+    not(feature = "ui"),
+    doc = "Without the `ui` feature, alerts are logged and sent as [`RaisedAlert`] events; see \
+           [`AlertBackend`] to present alerts some other way."
+)]
 //!
 //! Furthermore, this does not allow for any actual error maangement beyond displaying them.
 //! For errors that should be handled in more meaningful ways, consider using `system.pipe`
 //! directly or using `.pipe_err` from the `bevy_try_mod_system` crate.
 
-use bevy_ecs::prelude::*;
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use bevy_ecs::{prelude::*, system::SystemId};
+use bevy_time::{Real, Time};
+use bevy_utils::tracing::{debug, error};
+
+mod action;
+pub use action::*;
+mod alerts;
+pub use alerts::*;
+#[cfg(feature = "ui")]
+mod animation;
+#[cfg(feature = "ui")]
+pub use animation::*;
+mod backend;
+pub use backend::*;
+mod channel;
+pub use channel::*;
+#[cfg(feature = "ui")]
+mod clipboard;
+#[cfg(feature = "ui")]
+pub use clipboard::*;
+mod commands;
+pub use commands::*;
+mod console;
+pub use console::*;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::*;
+mod events;
+pub use events::*;
+#[cfg(feature = "file_log")]
+mod file_log;
+#[cfg(feature = "file_log")]
+pub use file_log::*;
+mod history;
+pub use history::*;
+mod localize;
+pub use localize::*;
+#[cfg(feature = "ui")]
+mod queue;
+#[cfg(feature = "ui")]
+pub use queue::*;
+mod sender;
+pub use sender::*;
+mod stats;
+pub use stats::*;
+mod style;
+pub use style::*;
+mod task;
+pub use task::*;
+#[cfg(any(test, feature = "test_utils"))]
+mod test_utils;
+#[cfg(any(test, feature = "test_utils"))]
+pub use test_utils::*;
+#[cfg(feature = "ui")]
+mod theme;
+#[cfg(feature = "ui")]
+pub use theme::*;
+mod message;
+pub use message::*;
+mod modal;
+pub use modal::*;
+#[cfg(feature = "ui")]
+mod mock;
+#[cfg(feature = "ui")]
+pub use mock::*;
+mod mute;
+pub use mute::*;
+#[cfg(feature = "serde")]
+mod persist;
+#[cfg(feature = "serde")]
+pub use persist::*;
+mod plugin;
+pub use plugin::*;
+mod report;
+pub use report::*;
 
 pub use anyhow;
-pub use bevy_ui_mod_alerts::AlertsPlugin;
+#[cfg(feature = "ui")]
+pub use bevy_ui_mod_alerts;
 
 pub type ResultVec<T, E> = std::result::Result<T, Vec<E>>;
 
+/// The `tracing` target of every event logged by this crate.
+pub const LOG_TARGET: &str = "bevy_anyhow_alert";
+
+/// The output of a piped alert system.
+///
+/// Implemented for `()`, which discards the `Ok` value, and for `Option<T>`, which forwards
+/// `Some(value)` on success and `None` after the alert has been raised.
+pub trait AlertOutput<T> {
+    /// Builds the output from the `Ok` value.
+    fn from_ok(value: T) -> Self;
+    /// Builds the output after an alert was raised.
+    fn from_err() -> Self;
+}
+
+impl<T> AlertOutput<T> for () {
+    fn from_ok(_: T) -> Self {}
+
+    fn from_err() -> Self {}
+}
+
+impl<T> AlertOutput<T> for Option<T> {
+    fn from_ok(value: T) -> Self {
+        Some(value)
+    }
+
+    fn from_err() -> Self {
+        None
+    }
+}
+
 /// Defines the `anyhow_alert` method which pipes system output to an Alert UI if the output
 /// is an error.
 ///
-/// This trait is implemented for all `IntoSystem` that return `Result<(), Err>`.
-pub trait AnyhowAlertExt<In, Err, Marker>
+/// This trait is implemented for all `IntoSystem` that return `Result<T, Err>`, including
+/// exclusive systems. `Err` can be any `Display` error, such as `anyhow::Error` or
+/// `Box<dyn Error + Send + Sync>`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn save_world(world: &mut World) -> anyhow::Result<()> { /* ... */ Ok(()) }
+///
+/// let mut app = App::new();
+/// app.add_systems(Update, save_world.anyhow_alert());
+/// ```
+pub trait AnyhowAlertExt<In, T, Err, Marker>
 where
     Err: std::fmt::Display + Send + Sync + 'static,
 {
     /// Pipes system output to an alert UI if the Result is Err.
-    fn anyhow_alert(self) -> impl System<In = In, Out = ()>;
+    ///
+    /// The resulting system outputs any [`AlertOutput`], so it can be added to a schedule
+    /// directly (`()`) or piped into a system accepting `In<Option<T>>`.
+    fn anyhow_alert<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with the given [`Severity`] if the Result is Err.
+    fn anyhow_alert_with_severity<Out>(self, severity: Severity) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with [`Severity::Warning`] if the Result is Err.
+    fn warn_alert<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with [`Severity::Info`] if the Result is Err.
+    fn info_alert<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err.
+    ///
+    /// The resulting system outputs the `Ok` value, or `T::default()` after raising the alert.
+    fn anyhow_alert_default(self) -> impl System<In = In, Out = T>
+    where
+        T: Default;
+
+    /// Pipes system output to an alert UI if the Result is Err, using `map` to build the alert
+    /// message instead of the error's `Display` output.
+    ///
+    /// `map` is only called when the system returns an error.
+    fn anyhow_alert_map<Out, Map>(self, map: Map) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Map: Fn(&Err) -> String + Send + Sync + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, prefixing the alert message
+    /// with `"{label}: "`.
+    fn anyhow_alert_context<Out>(self, label: impl Into<String>) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, dropping any further errors
+    /// from this system until `duration` has elapsed in [`Time<Real>`].
+    fn anyhow_alert_throttle<Out>(self, duration: Duration) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, but only the first time each
+    /// distinct message is returned by this system.
+    fn anyhow_alert_once<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, and also logs the error.
+    ///
+    /// See [`LOG_TARGET`] for the target of the logged events.
+    fn anyhow_alert_log<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err and `predicate` returns `true`
+    /// for the error.
+    ///
+    /// Errors rejected by `predicate` are dropped and logged at the debug level.
+    fn anyhow_alert_if<Out, Pred>(self, predicate: Pred) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Pred: Fn(&Err) -> bool + Send + Sync + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, showing the full chain of causes
+    /// of `anyhow::Error`s (e.g. `"loading settings: disk full"`).
+    ///
+    /// Other error types are shown with their `Display` output, like [`Self::anyhow_alert`].
+    fn anyhow_alert_chain<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, prefixing the alert message
+    /// with the name of the system (e.g. `"my_crate::systems::save_game: disk full"`).
+    fn anyhow_alert_named<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Panics if the Result is Err in debug builds, and pipes system output to an alert UI like
+    /// [`Self::anyhow_alert`] in release builds.
+    ///
+    /// The panic message includes the name of the system and the full chain of causes of
+    /// `anyhow::Error`s.
+    fn anyhow_alert_or_panic_debug<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, keeping the alert shown for
+    /// `ttl` instead of [`AlertConfig::ttl`].
+    ///
+    /// See [`Ttl`] for how zero and [`Duration::MAX`] are handled.
+    fn anyhow_alert_ttl<Out>(self, ttl: Duration) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to a [`Sticky`] alert UI if the Result is Err, which stays shown
+    /// until it is dismissed.
+    fn anyhow_alert_sticky<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with `priority` if the Result is Err.
+    ///
+    /// See [`AlertPriority`] for how it orders the alerts.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// fn autosave() -> anyhow::Result<()> { /* ... */ Ok(()) }
+    /// fn sync_lobby() -> anyhow::Result<()> { /* ... */ Ok(()) }
+    ///
+    /// let mut app = App::new();
+    /// app.add_systems(
+    ///     Update,
+    ///     (
+    ///         autosave.anyhow_alert_priority(-1),
+    ///         sync_lobby.anyhow_alert_priority(10),
+    ///     ),
+    /// );
+    /// ```
+    fn anyhow_alert_priority<Out>(self, priority: i32) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to a [`ModalAlert`] if the Result is Err, which blocks the rest of
+    /// the UI until its "OK" button is pressed.
+    ///
+    /// [`ModalAlertActive`] is set while it is shown.
+    fn anyhow_alert_modal<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI of `channel` if the Result is Err.
+    ///
+    /// See [`AlertsPlugin::with_channel`]; alerts raised to a channel that wasn't registered
+    /// are shown with the default alerts.
+    fn anyhow_alert_channel<Out>(
+        self,
+        channel: impl Into<String>,
+    ) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, inserting `M::default()` on the
+    /// alert entity.
+    ///
+    /// See [`AlertOptions::with_tag`] to combine it with other settings.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// #[derive(Component, Default)]
+    /// struct AudioAlert;
+    ///
+    /// fn play_music() -> anyhow::Result<()> { /* ... */ Ok(()) }
+    ///
+    /// let mut app = App::new();
+    /// app.add_systems(Update, play_music.anyhow_alert_tagged::<AudioAlert, ()>());
+    /// ```
+    fn anyhow_alert_tagged<M, Out>(self) -> impl System<In = In, Out = Out>
+    where
+        M: Component + Default,
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, with a "Retry" button running
+    /// `system` and dismissing the alert.
+    ///
+    /// See [`AlertAction`]; the button is hidden once `system` is removed.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// fn connect() -> anyhow::Result<()> { /* ... */ Ok(()) }
+    ///
+    /// let mut app = App::new();
+    /// let retry = app.world_mut().register_system(|| {
+    ///     let _ = connect();
+    /// });
+    /// app.add_systems(Startup, connect.anyhow_alert_with_retry(retry));
+    /// ```
+    fn anyhow_alert_with_retry<Out>(self, system: SystemId) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, with a button for each of
+    /// `actions` in order.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// fn join_server() -> anyhow::Result<()> { /* ... */ Ok(()) }
+    /// fn join_fallback_server() { /* ... */ }
+    ///
+    /// let mut app = App::new();
+    /// let fallback = app.world_mut().register_system(join_fallback_server);
+    /// let ignore = app.world_mut().register_system(|| {});
+    /// app.add_systems(
+    ///     Startup,
+    ///     join_server.anyhow_alert_actions(vec![
+    ///         AlertAction::new("Use fallback server", fallback),
+    ///         AlertAction::new("Ignore", ignore),
+    ///     ]),
+    /// );
+    /// ```
+    fn anyhow_alert_actions<Out>(
+        self,
+        actions: Vec<AlertAction>,
+    ) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI raised according to `options` if the Result is Err.
+    fn anyhow_alert_with<Out>(self, options: AlertOptions) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, with the [`AlertMeta`] built by
+    /// `meta` for the error.
+    fn anyhow_alert_meta<Out, Meta>(self, meta: Meta) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Meta: Fn(&Err) -> AlertMeta + Send + Sync + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, with the [`AlertMeta`] of the
+    /// error.
+    fn anyhow_alert_coded<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Err: HasAlertMeta;
+
+    /// Pipes system output to an alert UI if the Result is Err, showing the message the
+    /// [`AlertLocalizer`] has for the [`LocalizableError::l10n_key`] of the error.
+    fn anyhow_alert_localized<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Err: LocalizableError;
+
+    /// Pipes system output to an alert UI if the Result is Err, and also writes the error as an
+    /// [`ErrorEvent<Err>`].
+    fn anyhow_alert_and_emit<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Writes the error as an [`ErrorEvent<Err>`] if the Result is Err, without raising an alert.
+    fn anyhow_emit<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
 }
 
-impl<F, In, Err, Marker> AnyhowAlertExt<In, Err, Marker> for F
+impl<F, In, T, Err, Marker> AnyhowAlertExt<In, T, Err, Marker> for F
 where
-    F: IntoSystem<In, Result<(), Err>, Marker>,
-    Err: std::fmt::Debug + std::fmt::Display + Send + Sync + 'static,
+    F: IntoSystem<In, Result<T, Err>, Marker>,
+    T: Send + Sync + 'static,
+    Err: std::fmt::Display + Send + Sync + 'static,
     Marker: Send + Sync + 'static,
 {
-    fn anyhow_alert(self) -> impl System<In = In, Out = ()> {
+    fn anyhow_alert<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
         self.pipe(anyhow_alert_system)
     }
+
+    fn anyhow_alert_with_severity<Out>(self, severity: Severity) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(display_alert_pipe(severity))
+    }
+
+    fn warn_alert<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with_severity(Severity::Warning)
+    }
+
+    fn info_alert<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with_severity(Severity::Info)
+    }
+
+    fn anyhow_alert_default(self) -> impl System<In = In, Out = T>
+    where
+        T: Default,
+    {
+        self.pipe(anyhow_alert_default_system)
+    }
+
+    fn anyhow_alert_map<Out, Map>(self, map: Map) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Map: Fn(&Err) -> String + Send + Sync + 'static,
+    {
+        self.pipe(anyhow_alert_map_system(map))
+    }
+
+    fn anyhow_alert_context<Out>(self, label: impl Into<String>) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        let label = label.into();
+        self.anyhow_alert_map(move |error| format!("{label}: {error}"))
+    }
+
+    fn anyhow_alert_throttle<Out>(self, duration: Duration) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(throttled_alert_pipe(duration))
+    }
+
+    fn anyhow_alert_once<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(anyhow_alert_once_system)
+    }
+
+    fn anyhow_alert_log<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(anyhow_alert_log_system)
+    }
+
+    fn anyhow_alert_if<Out, Pred>(self, predicate: Pred) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Pred: Fn(&Err) -> bool + Send + Sync + 'static,
+    {
+        self.pipe(filtered_alert_pipe(predicate))
+    }
+
+    fn anyhow_alert_chain<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(alert_pipe(Severity::Error, format_chain))
+    }
+
+    fn anyhow_alert_named<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        let system = IntoSystem::into_system(self);
+        let name = system.name();
+        system.anyhow_alert_map(move |error| format!("{name}: {error}"))
+    }
+
+    fn anyhow_alert_or_panic_debug<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        let system = IntoSystem::into_system(self);
+        let name = system.name();
+        system.pipe(panic_or_alert_pipe(name, cfg!(debug_assertions)))
+    }
+
+    fn anyhow_alert_ttl<Out>(self, ttl: Duration) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().with_ttl(ttl))
+    }
+
+    fn anyhow_alert_sticky<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().sticky())
+    }
+
+    fn anyhow_alert_priority<Out>(self, priority: i32) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().with_priority(priority))
+    }
+
+    fn anyhow_alert_modal<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().modal())
+    }
+
+    fn anyhow_alert_channel<Out>(
+        self,
+        channel: impl Into<String>,
+    ) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().with_channel(channel))
+    }
+
+    fn anyhow_alert_tagged<M, Out>(self) -> impl System<In = In, Out = Out>
+    where
+        M: Component + Default,
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().with_tag::<M>())
+    }
+
+    fn anyhow_alert_with_retry<Out>(self, system: SystemId) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alert_with(AlertOptions::new().with_action(AlertAction::new("Retry", system)))
+    }
+
+    fn anyhow_alert_actions<Out>(self, actions: Vec<AlertAction>) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        let options = actions
+            .into_iter()
+            .fold(AlertOptions::new(), AlertOptions::with_action);
+        self.anyhow_alert_with(options)
+    }
+
+    fn anyhow_alert_with<Out>(self, options: AlertOptions) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(options_alert_pipe(options))
+    }
+
+    fn anyhow_alert_meta<Out, Meta>(self, meta: Meta) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Meta: Fn(&Err) -> AlertMeta + Send + Sync + 'static,
+    {
+        self.pipe(meta_alert_pipe(meta))
+    }
+
+    fn anyhow_alert_coded<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Err: HasAlertMeta,
+    {
+        self.anyhow_alert_meta(Err::alert_meta)
+    }
+
+    fn anyhow_alert_localized<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Err: LocalizableError,
+    {
+        self.pipe(localized_alert_pipe)
+    }
+
+    fn anyhow_alert_and_emit<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(emit_alert_pipe(true))
+    }
+
+    fn anyhow_emit<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(emit_alert_pipe(false))
+    }
 }
 
 /// The inner PipeableSystem used by [`AnyhowAlertExt`].
 ///
-/// Use this by piping a system that outputs a `Result<(), Err>` into this system.
+/// Use this by piping a system that outputs a `Result<T, Err>` into this system.
 ///
 /// ```
 /// use bevy::prelude::*;
 /// use bevy_anyhow_alert::*;
 /// fn my_system() -> anyhow::Result<()> { /* ... */ Ok(()) }
 /// // ...
-/// my_system.pipe(anyhow_alert_system);
+/// let mut app = App::new();
+/// app.add_systems(Update, my_system.pipe(anyhow_alert_system));
 /// ```
-pub fn anyhow_alert_system<Err>(In(input): In<Result<(), Err>>, commands: Commands)
+pub fn anyhow_alert_system<T, Out, Err>(In(input): In<Result<T, Err>>, alerts: Alerts) -> Out
 where
-    Err: std::fmt::Display,
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
 {
-    if let Err(error) = input {
-        AlertsPlugin::alert(In(vec![format!("{error}")]), commands)
-    }
+    display_result(input, alerts, Severity::Error)
 }
 
-/// Defines the `anyhow_alert` method which pipes system output to an Alert UI if the output
-/// `Vec<MyError>` is non-empty.
+/// The inner PipeableSystem used by [`AnyhowAlertExt::anyhow_alert_log`].
 ///
-/// This trait is implemented for all `IntoSystem` that return `Result<(), Vec<Err>>`.
-pub trait AnyhowAlertsExt<In, Err, Marker>
+/// Use this by piping a system that outputs a `Result<T, Err>` into this system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn my_system() -> anyhow::Result<()> { /* ... */ Ok(()) }
+/// // ...
+/// let mut app = App::new();
+/// app.add_systems(Update, my_system.pipe(anyhow_alert_log_system));
+/// ```
+pub fn anyhow_alert_log_system<T, Out, Err>(input: In<Result<T, Err>>, alerts: Alerts) -> Out
 where
-    Err: std::fmt::Debug + Send + Sync + 'static,
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
 {
-    /// Pipes system output to an alert UI if the Result is Err.
-    fn anyhow_alerts(self) -> impl System<In = In, Out = ()>;
+    if let Err(error) = &input.0 {
+        log_error(error);
+    }
+    anyhow_alert_system(input, alerts)
 }
 
-impl<F, In, Err, Marker> AnyhowAlertsExt<In, Vec<Err>, Marker> for F
+/// The inner PipeableSystem used by [`AnyhowAlertExt::anyhow_alert_once`].
+///
+/// Each distinct error message is only alerted the first time this system receives it.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn validate_save_file() -> anyhow::Result<()> { /* ... */ Ok(()) }
+/// // ...
+/// let mut app = App::new();
+/// app.add_systems(Update, validate_save_file.pipe(anyhow_alert_once_system));
+/// ```
+pub fn anyhow_alert_once_system<T, Out, Err>(
+    In(input): In<Result<T, Err>>,
+    mut alerts: Alerts,
+    mut seen: Local<HashSet<Arc<str>>>,
+) -> Out
 where
-    F: IntoSystem<In, Result<(), Vec<Err>>, Marker>,
-    Err: std::error::Error + Send + Sync + 'static,
-    Marker: Send + Sync + 'static,
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
 {
-    fn anyhow_alerts(self) -> impl System<In = In, Out = ()> {
-        self.pipe(anyhow_alerts_system)
+    match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            let message = alerts.message(ErrorMessage(&error));
+            if seen.insert(message.clone()) {
+                alerts.raise([message], Severity::Error);
+            }
+            Out::from_err()
+        }
     }
 }
 
-/// The inner PipeableSystem used by [`AnyhowAlertsExt`].
+/// Builds the inner PipeableSystem used by [`AnyhowAlertExt::anyhow_alert_map`].
 ///
-/// Use this by piping a system that outputs a `Result<(), Vec<Err>>` into this system.
+/// The returned system formats errors with `map` instead of their `Display` output.
 ///
 /// ```
 /// use bevy::prelude::*;
 /// use bevy_anyhow_alert::*;
-/// fn my_system() -> Result<(), Vec<anyhow::Error>> { /* ... */ Ok(()) }
+/// fn my_system() -> anyhow::Result<()> { /* ... */ Ok(()) }
 /// // ...
-/// my_system.pipe(anyhow_alerts_system);
+/// let mut app = App::new();
+/// app.add_systems(
+///     Update,
+///     my_system.pipe(anyhow_alert_map_system(|error: &anyhow::Error| format!("Oops: {error}"))),
+/// );
 /// ```
-pub fn anyhow_alerts_system<Err>(In(input): In<Result<(), Vec<Err>>>, commands: Commands)
+pub fn anyhow_alert_map_system<T, Out, Err>(
+    map: impl Fn(&Err) -> String + Send + Sync + 'static,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
 where
-    Err: std::fmt::Display,
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
 {
-    if let Err(errors) = input {
-        let messages = errors
-            .into_iter()
-            .map(|error| format!("{error}"))
-            .collect::<Vec<_>>();
-        AlertsPlugin::alert(In(messages), commands)
+    alert_pipe(Severity::Error, map)
+}
+
+/// Builds a PipeableSystem that raises alerts of `severity` with messages built by `format`.
+fn alert_pipe<T, Out, Err>(
+    severity: Severity,
+    format: impl Fn(&Err) -> String + Send + Sync + 'static,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: Send + Sync + 'static,
+{
+    move |In(input), alerts| alert_result(input, alerts, severity, &format)
+}
+
+/// Builds a PipeableSystem like [`anyhow_alert_system`] that raises alerts of `severity`.
+fn display_alert_pipe<T, Out, Err>(
+    severity: Severity,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), alerts| display_result(input, alerts, severity)
+}
+
+/// Builds a PipeableSystem that raises alerts at most once per `duration`.
+#[allow(clippy::type_complexity)]
+fn throttled_alert_pipe<T, Out, Err>(
+    duration: Duration,
+) -> impl FnMut(In<Result<T, Err>>, Alerts, Option<Res<Time<Real>>>, Local<Option<Duration>>) -> Out
+       + Send
+       + Sync
+       + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), alerts, time, mut last_alert| {
+        let now = time.map(|time| time.elapsed());
+        let throttled = now
+            .zip(*last_alert)
+            .is_some_and(|(now, last_alert)| now.saturating_sub(last_alert) < duration);
+        match input {
+            Err(_) if throttled => Out::from_err(),
+            input => {
+                if input.is_err() {
+                    *last_alert = now;
+                }
+                display_result(input, alerts, Severity::Error)
+            }
+        }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use bevy::prelude::*;
-    use bevy_ui_mod_alerts::Alert;
-    use thiserror::Error;
+/// Builds a PipeableSystem that panics on errors from the system called `name` if `panic` is
+/// `true`, and raises alerts otherwise.
+fn panic_or_alert_pipe<T, Out, Err>(
+    name: Cow<'static, str>,
+    panic: bool,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), alerts| match input {
+        Err(error) if panic => panic!("{name} returned an error: {}", format_chain(&error)),
+        input => display_result(input, alerts, Severity::Error),
+    }
+}
 
-    #[derive(Debug, Error)]
-    #[error("testing!")]
-    struct TestError;
+/// Builds a PipeableSystem that only raises alerts for errors accepted by `predicate`.
+fn filtered_alert_pipe<T, Out, Err>(
+    predicate: impl Fn(&Err) -> bool + Send + Sync + 'static,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), alerts| match input {
+        Err(error) if !predicate(&error) => {
+            debug!(target: LOG_TARGET, "dropped filtered alert: {error}");
+            Out::from_err()
+        }
+        input => display_result(input, alerts, Severity::Error),
+    }
+}
 
-    fn alternate_output(mut counter: Local<usize>) -> Result<(), TestError> {
-        *counter += 1;
-        if *counter % 2 == 1 {
-            Ok(())
-        } else {
-            Err(TestError)
+/// Builds a PipeableSystem that raises alerts with `options`.
+fn options_alert_pipe<T, Out, Err>(
+    options: AlertOptions,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), mut alerts| match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            let message = alerts.message(ErrorMessage(&error));
+            let raise = RaiseOptions {
+                style: alerts.style_of(&error),
+                payload: Some(AlertPayload::new(error)),
+                ..options.raise.clone()
+            };
+            alerts.raise_with([message], options.severity, raise);
+            Out::from_err()
         }
     }
+}
 
-    fn alternate_output_many_errors(mut counter: Local<usize>) -> ResultVec<(), TestError> {
-        *counter += 1;
-        if *counter % 2 == 1 {
-            Ok(())
-        } else {
-            Err(vec![TestError])
+/// Builds a PipeableSystem that raises alerts with the [`AlertMeta`] built by `meta`.
+fn meta_alert_pipe<T, Out, Err>(
+    meta: impl Fn(&Err) -> AlertMeta + Send + Sync + 'static,
+) -> impl FnMut(In<Result<T, Err>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), mut alerts| match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            let message = alerts.message(ErrorMessage(&error));
+            let options = RaiseOptions {
+                style: alerts.style_of(&error),
+                meta: Some(meta(&error)),
+                payload: Some(AlertPayload::new(error)),
+                ..Default::default()
+            };
+            alerts.raise_with([message], Severity::Error, options);
+            Out::from_err()
         }
     }
+}
 
-    fn app() -> App {
-        let mut app = App::new();
-        app.add_plugins(MinimalPlugins);
-        app.add_plugins(AlertsPlugin::new());
-        app
+/// A PipeableSystem that raises alerts with the [`AlertL10n`] of the error.
+fn localized_alert_pipe<T, Out, Err>(In(input): In<Result<T, Err>>, mut alerts: Alerts) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: LocalizableError + std::fmt::Display + Send + Sync + 'static,
+{
+    match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            let message = alerts.message(ErrorMessage(&error));
+            let options = RaiseOptions {
+                style: alerts.style_of(&error),
+                l10n: Some(AlertL10n::of(&error)),
+                payload: Some(AlertPayload::new(error)),
+                ..Default::default()
+            };
+            alerts.raise_with([message], Severity::Error, options);
+            Out::from_err()
+        }
     }
+}
 
-    #[test]
-    fn test_one_error_system() {
-        let mut app = app();
-        app.add_systems(Update, alternate_output.anyhow_alert());
-        let mut query = app.world_mut().query::<&Alert>();
-        assert_eq!(query.iter(app.world()).count(), 0);
-        app.update();
-        assert_eq!(query.iter(app.world()).count(), 0);
-        app.update();
-        assert_eq!(query.iter(app.world()).count(), 1);
-        app.update();
-        assert_eq!(query.iter(app.world()).count(), 1);
-        app.update();
-        assert_eq!(query.iter(app.world()).count(), 2);
+/// Builds a PipeableSystem that writes errors as [`ErrorEvent`]s, also raising an alert if
+/// `raise` is `true`.
+fn emit_alert_pipe<T, Out, Err>(
+    raise: bool,
+) -> impl FnMut(In<Result<T, Err>>, Alerts, ErrorEvents<Err>) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), mut alerts, mut events| match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            if raise {
+                alerts.raise([ErrorMessage(&error)], Severity::Error);
+            }
+            events.send(error);
+            Out::from_err()
+        }
     }
+}
 
-    #[test]
-    fn test_error_collecting_system() {
-        let mut app = app();
+/// Raises an alert showing the `Display` output of the error if `input` is an error.
+///
+/// `String` errors are used as the alert message instead of being formatted again.
+fn display_result<T, Out, Err>(input: Result<T, Err>, mut alerts: Alerts, severity: Severity) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            let message = alerts.message(ErrorMessage(&error));
+            alert_result(Err(error), alerts, severity, |_| message)
+        }
+    }
+}
+
+/// Raises an alert with the message built by `format` if `input` is an error.
+fn alert_result<T, Out, Err, M>(
+    input: Result<T, Err>,
+    mut alerts: Alerts,
+    severity: Severity,
+    format: impl FnOnce(&Err) -> M,
+) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: Send + Sync + 'static,
+    M: IntoMessage,
+{
+    match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => {
+            let message = format(&error);
+            let options = RaiseOptions {
+                style: alerts.style_of(&error),
+                payload: Some(AlertPayload::new(error)),
+                ..Default::default()
+            };
+            alerts.raise_with([message], severity, options);
+            Out::from_err()
+        }
+    }
+}
+
+/// The inner PipeableSystem used by [`AnyhowAlertExt::anyhow_alert_default`].
+///
+/// Use this by piping a system that outputs a `Result<T, Err>` into this system, where
+/// `T: Default`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn my_system() -> anyhow::Result<u32> { /* ... */ Ok(0) }
+/// fn use_value(In(value): In<u32>) { /* ... */ }
+/// // ...
+/// let mut app = App::new();
+/// app.add_systems(Update, my_system.pipe(anyhow_alert_default_system).pipe(use_value));
+/// ```
+pub fn anyhow_alert_default_system<T, Err>(input: In<Result<T, Err>>, alerts: Alerts) -> T
+where
+    T: Default,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    anyhow_alert_system::<T, Option<T>, Err>(input, alerts).unwrap_or_default()
+}
+
+/// Defines the `anyhow_alert_except` method which routes one specific error type to a handler
+/// and pipes every other error to an Alert UI.
+///
+/// This trait is implemented for all `IntoSystem` that return `anyhow::Result<T>`.
+pub trait AnyhowAlertExceptExt<In, T, Marker> {
+    /// Pipes system output to `handler` if the Result is an `anyhow::Error` that downcasts to
+    /// `E`, and to an alert UI for any other error.
+    ///
+    /// No alert is raised for errors passed to `handler`.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// #[derive(Debug, thiserror::Error)]
+    /// #[error("save file is corrupt")]
+    /// struct SaveCorrupt;
+    ///
+    /// #[derive(Resource)]
+    /// struct RecoverSave;
+    ///
+    /// fn load_save() -> anyhow::Result<()> { /* ... */ Ok(()) }
+    /// // ...
+    /// let mut app = App::new();
+    /// app.add_systems(
+    ///     Update,
+    ///     load_save.anyhow_alert_except(|_: SaveCorrupt, commands: &mut Commands| {
+    ///         commands.insert_resource(RecoverSave);
+    ///     }),
+    /// );
+    /// ```
+    fn anyhow_alert_except<E, Out>(
+        self,
+        handler: impl Fn(E, &mut Commands) + Send + Sync + 'static,
+    ) -> impl System<In = In, Out = Out>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+        Out: AlertOutput<T> + 'static;
+}
+
+impl<F, In, T, Marker> AnyhowAlertExceptExt<In, T, Marker> for F
+where
+    F: IntoSystem<In, anyhow::Result<T>, Marker>,
+    T: Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn anyhow_alert_except<E, Out>(
+        self,
+        handler: impl Fn(E, &mut Commands) + Send + Sync + 'static,
+    ) -> impl System<In = In, Out = Out>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(except_alert_pipe(handler))
+    }
+}
+
+/// Builds a PipeableSystem that passes errors downcasting to `E` to `handler` instead of
+/// raising an alert.
+fn except_alert_pipe<T, Out, E>(
+    handler: impl Fn(E, &mut Commands) + Send + Sync + 'static,
+) -> impl FnMut(In<anyhow::Result<T>>, Commands, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    move |In(input), mut commands, mut alerts| match input {
+        Ok(value) => Out::from_ok(value),
+        Err(error) => match error.downcast::<E>() {
+            Ok(error) => {
+                handler(error, &mut commands);
+                Out::from_err()
+            }
+            Err(error) => {
+                let message = alerts.message(Formatted(&error));
+                alert_result(Err(error), alerts, Severity::Error, |_| message)
+            }
+        },
+    }
+}
+
+/// Defines the `anyhow_alert_debug` method which pipes system output to an Alert UI showing the
+/// `Debug` output of the error.
+///
+/// This trait is implemented for all `IntoSystem` that return `Result<T, Err>` where
+/// `Err: Debug`, so error types without a `Display` impl can be alerted too.
+pub trait AnyhowAlertDebugExt<In, T, Err, Marker>
+where
+    Err: std::fmt::Debug + Send + Sync + 'static,
+{
+    /// Pipes system output to an alert UI showing `format!("{error:?}")` if the Result is Err.
+    fn anyhow_alert_debug<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+}
+
+impl<F, In, T, Err, Marker> AnyhowAlertDebugExt<In, T, Err, Marker> for F
+where
+    F: IntoSystem<In, Result<T, Err>, Marker>,
+    T: Send + Sync + 'static,
+    Err: std::fmt::Debug + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn anyhow_alert_debug<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(alert_pipe(Severity::Error, |error: &Err| {
+            format!("{error:?}")
+        }))
+    }
+}
+
+/// Defines the `anyhow_alerts_debug` method which pipes system output to an Alert UI showing the
+/// `Debug` output of each error.
+///
+/// This trait is implemented for all `IntoSystem` that return `Result<T, Vec<Err>>` where
+/// `Err: Debug`.
+pub trait AnyhowAlertsDebugExt<In, T, Err, Marker>
+where
+    Err: std::fmt::Debug + Send + Sync + 'static,
+{
+    /// Pipes system output to an alert UI showing `format!("{error:?}")` for each error if the
+    /// Result is Err.
+    fn anyhow_alerts_debug<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+}
+
+impl<F, In, T, Err, Marker> AnyhowAlertsDebugExt<In, T, Err, Marker> for F
+where
+    F: IntoSystem<In, Result<T, Vec<Err>>, Marker>,
+    T: Send + Sync + 'static,
+    Err: std::fmt::Debug + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn anyhow_alerts_debug<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(alerts_pipe(Severity::Error, |error: &Err| {
+            format!("{error:?}")
+        }))
+    }
+}
+
+/// Defines the `option_alert` method which pipes system output to an Alert UI if the output
+/// is `Some` error.
+///
+/// This trait is implemented for all `IntoSystem` that return `Option<Err>`.
+pub trait OptionAlertExt<In, Err, Marker>
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    /// Pipes system output to an alert UI if the Option is Some.
+    fn option_alert(self) -> impl System<In = In, Out = ()>;
+}
+
+impl<F, In, Err, Marker> OptionAlertExt<In, Err, Marker> for F
+where
+    F: IntoSystem<In, Option<Err>, Marker>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn option_alert(self) -> impl System<In = In, Out = ()> {
+        self.pipe(option_alert_system)
+    }
+}
+
+/// The inner PipeableSystem used by [`OptionAlertExt`].
+///
+/// Use this by piping a system that outputs an `Option<Err>` into this system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn my_system() -> Option<anyhow::Error> { /* ... */ None }
+/// // ...
+/// my_system.pipe(option_alert_system);
+/// ```
+pub fn option_alert_system<Err>(In(input): In<Option<Err>>, alerts: Alerts)
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    anyhow_alert_system::<(), (), Err>(In(input.map_or(Ok(()), Err)), alerts)
+}
+
+/// Defines the `alert_all` method which pipes system output to an Alert UI for each error of the
+/// output `Vec<Err>`.
+///
+/// This trait is implemented for all `IntoSystem` that return `Vec<Err>`; an empty `Vec` means the
+/// system succeeded. See [`AnyhowAlertsExt`] for systems returning a [`ResultVec`].
+pub trait AlertAllExt<In, Err, Marker>
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    /// Pipes system output to an alert UI for each error.
+    fn alert_all(self) -> impl System<In = In, Out = ()>;
+}
+
+impl<F, In, Err, Marker> AlertAllExt<In, Err, Marker> for F
+where
+    F: IntoSystem<In, Vec<Err>, Marker>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn alert_all(self) -> impl System<In = In, Out = ()> {
+        self.pipe(alert_all_system)
+    }
+}
+
+/// The inner PipeableSystem used by [`AlertAllExt`].
+///
+/// Use this by piping a system that outputs a `Vec<Err>` into this system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn my_system() -> Vec<anyhow::Error> { /* ... */ vec![] }
+/// // ...
+/// let mut app = App::new();
+/// app.add_systems(Update, my_system.pipe(alert_all_system));
+/// ```
+pub fn alert_all_system<Err>(In(errors): In<Vec<Err>>, alerts: Alerts)
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    if !errors.is_empty() {
+        display_results::<(), (), Err>(Err(errors), alerts, Severity::Error);
+    }
+}
+
+/// Defines the `alert_errs` method which pipes system output to an Alert UI for each `Err` of the
+/// output `Result`s.
+///
+/// This trait is implemented for all `IntoSystem` that return an `IntoIterator` of
+/// `Result<T, Err>`, such as `Vec<Result<T, Err>>`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// #[derive(Component)]
+/// struct Npc;
+///
+/// fn find_path(npc: Entity) -> anyhow::Result<()> { /* ... */ Ok(()) }
+///
+/// fn find_paths(npcs: Query<Entity, With<Npc>>) -> Vec<anyhow::Result<()>> {
+///     npcs.iter().map(find_path).collect()
+/// }
+///
+/// let mut app = App::new();
+/// app.add_systems(Update, find_paths.alert_errs());
+/// ```
+pub trait AlertErrsExt<In, I, T, Err, Marker>
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    /// Pipes system output to an alert UI for each `Err`.
+    ///
+    /// The resulting system outputs the `Ok` values collected into any `FromIterator<T>`, such
+    /// as `()` or `Vec<T>`.
+    fn alert_errs<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: FromIterator<T> + 'static;
+}
+
+impl<F, In, I, T, Err, Marker> AlertErrsExt<In, I, T, Err, Marker> for F
+where
+    F: IntoSystem<In, I, Marker>,
+    I: IntoIterator<Item = Result<T, Err>> + 'static,
+    T: 'static,
+    Err: std::fmt::Display + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn alert_errs<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: FromIterator<T> + 'static,
+    {
+        self.pipe(alert_errs_system)
+    }
+}
+
+/// The inner PipeableSystem used by [`AlertErrsExt`].
+///
+/// Use this by piping a system that outputs an `IntoIterator` of `Result<T, Err>` into this
+/// system.
+pub fn alert_errs_system<I, T, Out, Err>(In(results): In<I>, alerts: Alerts) -> Out
+where
+    I: IntoIterator<Item = Result<T, Err>>,
+    Out: FromIterator<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    let mut errors = Vec::new();
+    let values = results
+        .into_iter()
+        .filter_map(|result| result.map_err(|error| errors.push(error)).ok())
+        .collect();
+    if !errors.is_empty() {
+        display_results::<(), (), Err>(Err(errors), alerts, Severity::Error);
+    }
+    values
+}
+
+/// Defines the `anyhow_alerts` method which pipes system output to an Alert UI if the output
+/// `Vec<MyError>` is non-empty.
+///
+/// This trait is implemented for all `IntoSystem` that return `Result<T, Vec<Err>>`. Like
+/// [`AnyhowAlertExt`], the resulting systems output any [`AlertOutput`], such as `Option<T>`.
+pub trait AnyhowAlertsExt<In, T, Err, Marker>
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    /// Pipes system output to an alert UI if the Result is Err.
+    fn anyhow_alerts<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with the given [`Severity`] if the Result is Err.
+    fn anyhow_alerts_with_severity<Out>(
+        self,
+        severity: Severity,
+    ) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with [`Severity::Warning`] if the Result is Err.
+    fn warn_alerts<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI with [`Severity::Info`] if the Result is Err.
+    fn info_alerts<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, prefixing each alert message
+    /// with `"{label}: "`.
+    fn anyhow_alerts_context<Out>(
+        self,
+        label: impl Into<String>,
+    ) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, and also logs each error.
+    ///
+    /// See [`LOG_TARGET`] for the target of the logged events.
+    fn anyhow_alerts_log<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, showing the full chain of causes
+    /// of `anyhow::Error`s.
+    ///
+    /// Other error types are shown with their `Display` output, like [`Self::anyhow_alerts`].
+    fn anyhow_alerts_chain<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, prefixing each alert message
+    /// with the name of the system.
+    fn anyhow_alerts_named<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, keeping each alert shown for
+    /// `ttl` instead of [`AlertConfig::ttl`].
+    ///
+    /// See [`Ttl`] for how zero and [`Duration::MAX`] are handled.
+    fn anyhow_alerts_ttl<Out>(self, ttl: Duration) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to [`Sticky`] alert UIs if the Result is Err, which stay shown until
+    /// they are dismissed.
+    fn anyhow_alerts_sticky<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, only raising alerts for the
+    /// errors for which `predicate` returns `true`.
+    ///
+    /// Errors rejected by `predicate` are dropped and logged at the debug level.
+    fn anyhow_alerts_if<Out, Pred>(self, predicate: Pred) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Pred: Fn(&Err) -> bool + Send + Sync + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, raising a single alert such as
+    /// `"312 errors: first is 'row 1 is invalid' (see log)"` when there are more than
+    /// `threshold` errors. A single error is never summarized, even with a `threshold` of 0.
+    ///
+    /// The summarized errors are logged and recorded in the [`AlertHistory`] instead.
+    fn anyhow_alerts_summarized<Out>(self, threshold: usize) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+
+    /// Pipes system output to an alert UI if the Result is Err, raising one alert for each
+    /// distinct message with its number of errors as the [`AlertCount`].
+    ///
+    /// The alerts are raised from the most to the least common message.
+    fn anyhow_alerts_grouped<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static;
+}
+
+impl<F, In, T, Err, Marker> AnyhowAlertsExt<In, T, Err, Marker> for F
+where
+    F: IntoSystem<In, Result<T, Vec<Err>>, Marker>,
+    T: Send + Sync + 'static,
+    Err: std::fmt::Display + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn anyhow_alerts<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(anyhow_alerts_system)
+    }
+
+    fn anyhow_alerts_with_severity<Out>(self, severity: Severity) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(display_alerts_pipe(severity))
+    }
+
+    fn warn_alerts<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alerts_with_severity(Severity::Warning)
+    }
+
+    fn info_alerts<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.anyhow_alerts_with_severity(Severity::Info)
+    }
+
+    fn anyhow_alerts_context<Out>(self, label: impl Into<String>) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        let label = label.into();
+        self.pipe(alerts_pipe(Severity::Error, move |error: &Err| {
+            format!("{label}: {error}")
+        }))
+    }
+
+    fn anyhow_alerts_log<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(anyhow_alerts_log_system)
+    }
+
+    fn anyhow_alerts_chain<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(alerts_pipe(Severity::Error, format_chain))
+    }
+
+    fn anyhow_alerts_named<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        let system = IntoSystem::into_system(self);
+        let name = system.name();
+        system.pipe(alerts_pipe(Severity::Error, move |error: &Err| {
+            format!("{name}: {error}")
+        }))
+    }
+
+    fn anyhow_alerts_ttl<Out>(self, ttl: Duration) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(expiry_alerts_pipe(Expiry::After(ttl)))
+    }
+
+    fn anyhow_alerts_sticky<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(expiry_alerts_pipe(Expiry::Sticky))
+    }
+
+    fn anyhow_alerts_if<Out, Pred>(self, predicate: Pred) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+        Pred: Fn(&Err) -> bool + Send + Sync + 'static,
+    {
+        self.pipe(filtered_alerts_pipe(predicate))
+    }
+
+    fn anyhow_alerts_summarized<Out>(self, threshold: usize) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(summarized_alerts_pipe(threshold))
+    }
+
+    fn anyhow_alerts_grouped<Out>(self) -> impl System<In = In, Out = Out>
+    where
+        Out: AlertOutput<T> + 'static,
+    {
+        self.pipe(grouped_alerts_system)
+    }
+}
+
+/// The inner PipeableSystem used by [`AnyhowAlertsExt`].
+///
+/// Use this by piping a system that outputs a `Result<T, Vec<Err>>` into this system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn my_system() -> Result<(), Vec<anyhow::Error>> { /* ... */ Ok(()) }
+/// // ...
+/// let mut app = App::new();
+/// app.add_systems(Update, my_system.pipe(anyhow_alerts_system));
+/// ```
+pub fn anyhow_alerts_system<T, Out, Err>(In(input): In<Result<T, Vec<Err>>>, alerts: Alerts) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    display_results(input, alerts, Severity::Error)
+}
+
+/// The inner PipeableSystem used by [`AnyhowAlertsExt::anyhow_alerts_log`].
+///
+/// Use this by piping a system that outputs a `Result<T, Vec<Err>>` into this system.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// fn my_system() -> Result<(), Vec<anyhow::Error>> { /* ... */ Ok(()) }
+/// // ...
+/// let mut app = App::new();
+/// app.add_systems(Update, my_system.pipe(anyhow_alerts_log_system));
+/// ```
+pub fn anyhow_alerts_log_system<T, Out, Err>(input: In<Result<T, Vec<Err>>>, alerts: Alerts) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    if let Err(errors) = &input.0 {
+        errors.iter().for_each(log_error);
+    }
+    anyhow_alerts_system(input, alerts)
+}
+
+/// The output of a system that partially succeeded: the value it built, and the errors it
+/// collected along the way.
+///
+/// See [`PartialAlertsExt`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Partial<T, E> {
+    /// The value built by the system.
+    pub value: T,
+    /// The errors collected by the system.
+    pub errors: Vec<E>,
+}
+
+impl<T, E> Partial<T, E> {
+    /// Builds a `Partial` with `value` and no errors.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            errors: Vec::new(),
+        }
+    }
+}
+
+/// Defines the `partial_alerts` method which pipes the errors of a [`Partial`] system output to
+/// an Alert UI, forwarding its value.
+///
+/// This trait is implemented for all `IntoSystem` that return `Partial<T, Err>`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn load_levels() -> Partial<Vec<String>, anyhow::Error> { /* ... */ Partial::new(vec![]) }
+///
+/// fn show_levels(In(levels): In<Vec<String>>) { /* ... */ }
+///
+/// let mut app = App::new();
+/// app.add_systems(Update, load_levels.partial_alerts().pipe(show_levels));
+/// ```
+pub trait PartialAlertsExt<In, T, Err, Marker>
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    /// Pipes the errors of the system output to an alert UI, and outputs its value.
+    fn partial_alerts(self) -> impl System<In = In, Out = T>;
+}
+
+impl<F, In, T, Err, Marker> PartialAlertsExt<In, T, Err, Marker> for F
+where
+    F: IntoSystem<In, Partial<T, Err>, Marker>,
+    T: Send + Sync + 'static,
+    Err: std::fmt::Display + Send + Sync + 'static,
+    Marker: Send + Sync + 'static,
+{
+    fn partial_alerts(self) -> impl System<In = In, Out = T> {
+        self.pipe(partial_alerts_system)
+    }
+}
+
+/// The inner PipeableSystem used by [`PartialAlertsExt`].
+///
+/// Use this by piping a system that outputs a `Partial<T, Err>` into this system.
+pub fn partial_alerts_system<T, Err>(In(input): In<Partial<T, Err>>, alerts: Alerts) -> T
+where
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    let Partial { value, errors } = input;
+    if !errors.is_empty() {
+        display_results::<(), (), Err>(Err(errors), alerts, Severity::Error);
+    }
+    value
+}
+
+/// Logs `error`, including the full chain of causes for `anyhow::Error`s.
+fn log_error<Err>(error: &Err)
+where
+    Err: std::fmt::Display + 'static,
+{
+    error!(target: LOG_TARGET, "{}", format_chain(error));
+}
+
+/// Formats `error` with the full chain of causes for `anyhow::Error`s, and with its `Display`
+/// output otherwise.
+fn format_chain<Err>(error: &Err) -> String
+where
+    Err: std::fmt::Display + 'static,
+{
+    match (error as &dyn Any).downcast_ref::<anyhow::Error>() {
+        Some(error) => format!("{error:#}"),
+        None => format!("{error}"),
+    }
+}
+
+/// Builds a PipeableSystem like [`anyhow_alerts_system`] that raises alerts of `severity` with
+/// messages built by `format`.
+fn alerts_pipe<T, Out, Err>(
+    severity: Severity,
+    format: impl Fn(&Err) -> String + Send + Sync + 'static,
+) -> impl FnMut(In<Result<T, Vec<Err>>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: Send + Sync + 'static,
+{
+    move |In(input), alerts| alert_results(input, alerts, severity, &format)
+}
+
+/// Builds a PipeableSystem like [`anyhow_alerts_system`] that raises alerts of `severity`.
+fn display_alerts_pipe<T, Out, Err>(
+    severity: Severity,
+) -> impl FnMut(In<Result<T, Vec<Err>>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), alerts| display_results(input, alerts, severity)
+}
+
+/// Builds a PipeableSystem like [`anyhow_alerts_system`] that raises alerts expiring according
+/// to `expiry`.
+fn expiry_alerts_pipe<T, Out, Err>(
+    expiry: Expiry,
+) -> impl FnMut(In<Result<T, Vec<Err>>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), mut alerts| match input {
+        Ok(value) => Out::from_ok(value),
+        Err(errors) => {
+            let messages = errors.iter().map(ErrorMessage);
+            let options = RaiseOptions {
+                expiry,
+                ..Default::default()
+            };
+            alerts.raise_with(messages, Severity::Error, options);
+            Out::from_err()
+        }
+    }
+}
+
+/// Builds a PipeableSystem like [`anyhow_alerts_system`] that only raises alerts for the errors
+/// accepted by `predicate`.
+fn filtered_alerts_pipe<T, Out, Err>(
+    predicate: impl Fn(&Err) -> bool + Send + Sync + 'static,
+) -> impl FnMut(In<Result<T, Vec<Err>>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), alerts| {
+        let input = input.map_err(|errors| {
+            errors
+                .into_iter()
+                .filter(|error| {
+                    let accepted = predicate(error);
+                    if !accepted {
+                        debug!(target: LOG_TARGET, "dropped filtered alert: {error}");
+                    }
+                    accepted
+                })
+                .collect::<Vec<_>>()
+        });
+        display_results(input, alerts, Severity::Error)
+    }
+}
+
+/// Builds a PipeableSystem like [`anyhow_alerts_system`] that raises a single alert summarizing
+/// the errors when there are more than `threshold` of them.
+fn summarized_alerts_pipe<T, Out, Err>(
+    threshold: usize,
+) -> impl FnMut(In<Result<T, Vec<Err>>>, Alerts) -> Out + Send + Sync + 'static
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    move |In(input), mut alerts| match input {
+        Err(errors) if errors.len() > threshold.max(1) => {
+            errors.iter().for_each(log_error);
+            let summarized = errors
+                .iter()
+                .map(|error| alerts.message(ErrorMessage(error)))
+                .collect::<Vec<_>>();
+            let message = format_args!(
+                "{} errors: first is '{}' (see log)",
+                summarized.len(),
+                summarized[0]
+            );
+            let message = alerts.message(Formatted(&message));
+            let options = RaiseOptions {
+                summarized,
+                ..Default::default()
+            };
+            alerts.raise_with([message], Severity::Error, options);
+            Out::from_err()
+        }
+        input => display_results(input, alerts, Severity::Error),
+    }
+}
+
+/// Raises an alert for each distinct message of the errors if `input` is an error, from the
+/// most to the least common.
+fn grouped_alerts_system<T, Out, Err>(In(input): In<Result<T, Vec<Err>>>, mut alerts: Alerts) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + Send + Sync + 'static,
+{
+    let errors = match input {
+        Ok(value) => return Out::from_ok(value),
+        Err(errors) => errors,
+    };
+    let mut groups = Vec::<(Arc<str>, usize)>::new();
+    let mut indices = HashMap::<Arc<str>, usize>::new();
+    for error in &errors {
+        let message = alerts.message(ErrorMessage(error));
+        match indices.get(&message) {
+            Some(&index) => groups[index].1 += 1,
+            None => {
+                indices.insert(message.clone(), groups.len());
+                groups.push((message, 1));
+            }
+        }
+    }
+    // the sort is stable, so messages as common keep the order of their first error
+    groups.sort_by(|(_, a), (_, b)| b.cmp(a));
+    for (message, count) in groups {
+        let options = RaiseOptions {
+            count: Some(count),
+            ..Default::default()
+        };
+        alerts.raise_with([message], Severity::Error, options);
+    }
+    Out::from_err()
+}
+
+/// Raises an alert showing the `Display` output of each error if `input` is an error.
+///
+/// `String` errors are used as the alert messages instead of being formatted again.
+fn display_results<T, Out, Err>(
+    input: Result<T, Vec<Err>>,
+    mut alerts: Alerts,
+    severity: Severity,
+) -> Out
+where
+    Out: AlertOutput<T>,
+    Err: std::fmt::Display + 'static,
+{
+    match input {
+        Ok(value) => Out::from_ok(value),
+        Err(errors) => {
+            alerts.raise(errors.iter().map(ErrorMessage), severity);
+            Out::from_err()
+        }
+    }
+}
+
+/// Raises an alert for each error with the message built by `format` if `input` is an error.
+fn alert_results<T, Out, Err>(
+    input: Result<T, Vec<Err>>,
+    mut alerts: Alerts,
+    severity: Severity,
+    format: impl Fn(&Err) -> String,
+) -> Out
+where
+    Out: AlertOutput<T>,
+{
+    match input {
+        Ok(value) => Out::from_ok(value),
+        Err(errors) => {
+            alerts.raise(errors.iter().map(format), severity);
+            Out::from_err()
+        }
+    }
+}
+
+/// Defines the `or_alert` methods which raise an alert for an `Err` while a system keeps going.
+///
+/// This trait is implemented for all `Result<T, Err>`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn load_levels(mut alerts: Alerts) {
+///     for level in ["forest", "cave"] {
+///         if let Some(data) = load_level(level).or_alert(&mut alerts) {
+///             // ...
+///         }
+///     }
+/// }
+///
+/// fn load_level(name: &str) -> anyhow::Result<Vec<u8>> { /* ... */ Ok(vec![]) }
+/// # let mut app = App::new();
+/// # app.add_systems(Update, load_levels);
+/// ```
+///
+/// Each call raises its own alert, so several errors in one system run are all shown.
+pub trait ResultAlertExt<T, Err> {
+    /// Raises an alert if the Result is Err, converting it into an `Option`.
+    fn or_alert(self, alerts: &mut Alerts) -> Option<T>;
+
+    /// Raises an alert with the message built by `format` if the Result is Err, converting it
+    /// into an `Option`.
+    fn ok_or_alert_with(
+        self,
+        alerts: &mut Alerts,
+        format: impl FnOnce(&Err) -> String,
+    ) -> Option<T>;
+}
+
+impl<T, Err> ResultAlertExt<T, Err> for Result<T, Err>
+where
+    Err: std::fmt::Display,
+{
+    fn or_alert(self, alerts: &mut Alerts) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(error) => {
+                alerts.push(error);
+                None
+            }
+        }
+    }
+
+    fn ok_or_alert_with(
+        self,
+        alerts: &mut Alerts,
+        format: impl FnOnce(&Err) -> String,
+    ) -> Option<T> {
+        match self {
+            Ok(value) => Some(value),
+            Err(error) => {
+                alerts.push(format(&error));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ui"))]
+mod tests {
+    use super::*;
+    use bevy::core::FrameCount;
+    use bevy::ecs::{schedule::ExecutorKind, world::CommandQueue};
+    use bevy::prelude::*;
+    use bevy::ui::FocusPolicy;
+    use bevy::utils::tracing::{
+        field::{Field, Visit},
+        Event, Level, Subscriber,
+    };
+    use bevy::{ecs::schedule::ScheduleLabel, time::TimeUpdateStrategy};
+    use bevy_ui_mod_alerts::{Alert, AlertUi, AlertUiRoot, DismissButton};
+    use std::sync::{Arc, Mutex};
+    use thiserror::Error;
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+    #[derive(Debug, Error)]
+    #[error("testing!")]
+    struct TestError;
+
+    fn alternate_output(mut counter: Local<usize>) -> Result<(), TestError> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            Ok(())
+        } else {
+            Err(TestError)
+        }
+    }
+
+    fn alternate_output_value(mut counter: Local<usize>) -> Result<u32, TestError> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            Ok(*counter as u32)
+        } else {
+            Err(TestError)
+        }
+    }
+
+    fn count_frames(mut counter: Local<u32>) -> u32 {
+        *counter += 1;
+        *counter
+    }
+
+    fn reject_even(In(value): In<u32>) -> Result<u32, TestError> {
+        if value % 2 == 1 {
+            Ok(value)
+        } else {
+            Err(TestError)
+        }
+    }
+
+    #[derive(Default, Resource)]
+    struct ReceivedValues(Vec<Option<u32>>);
+
+    fn record_value(In(value): In<Option<u32>>, mut received: ResMut<ReceivedValues>) {
+        received.0.push(value);
+    }
+
+    #[derive(Default, Resource)]
+    struct ReceivedDefaults(Vec<u32>);
+
+    fn record_default(In(value): In<u32>, mut received: ResMut<ReceivedDefaults>) {
+        received.0.push(value);
+    }
+
+    fn alternate_option_output(mut counter: Local<usize>) -> Option<TestError> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            None
+        } else {
+            Some(TestError)
+        }
+    }
+
+    fn alternate_output_many_errors(mut counter: Local<usize>) -> ResultVec<(), TestError> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            Ok(())
+        } else {
+            Err(vec![TestError])
+        }
+    }
+
+    fn alternate_output_many_anyhow_errors(
+        mut counter: Local<usize>,
+    ) -> ResultVec<(), anyhow::Error> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            Ok(())
+        } else {
+            Err(vec![
+                anyhow::Error::new(TestError),
+                anyhow::anyhow!("another test!"),
+            ])
+        }
+    }
+
+    /// An error type that implements `Display` but neither `Debug` nor `std::error::Error`.
+    struct DisplayOnlyError;
+
+    impl std::fmt::Display for DisplayOnlyError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "display only!")
+        }
+    }
+
+    fn display_only_errors() -> ResultVec<(), DisplayOnlyError> {
+        Err(vec![DisplayOnlyError])
+    }
+
+    /// Counts every raised alert, including repeats folded into an existing alert.
+    fn raised_count(world: &mut World) -> usize {
+        let mut query = world.query_filtered::<&AlertCount, With<Alert>>();
+        query.iter(world).map(|count| count.0).sum()
+    }
+
+    /// Collects the text of every alert that has been spawned in the UI.
+    fn alert_texts(world: &mut World) -> Vec<String> {
+        let mut query = world.query::<(&Name, &Text)>();
+        query
+            .iter(world)
+            .filter(|(name, _)| name.as_str() == "Alert Text")
+            .map(|(_, text)| {
+                text.sections
+                    .iter()
+                    .map(|section| section.value.as_str())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[derive(Debug, Error)]
+    #[error("disk full")]
+    struct DiskFullError;
+
+    fn disk_full() -> Result<(), DiskFullError> {
+        Err(DiskFullError)
+    }
+
+    fn disk_full_many() -> ResultVec<(), DiskFullError> {
+        Err(vec![DiskFullError])
+    }
+
+    fn alternate_error_kinds(mut counter: Local<usize>) -> anyhow::Result<()> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            Err(TestError.into())
+        } else {
+            Err(DiskFullError.into())
+        }
+    }
+
+    fn mixed_error_kinds() -> ResultVec<(), anyhow::Error> {
+        Err(vec![TestError.into(), DiskFullError.into()])
+    }
+
+    #[derive(Debug, Error)]
+    #[error("save file is corrupt")]
+    struct SaveCorrupt;
+
+    #[derive(Default, Resource)]
+    struct RecoveredSaves(usize);
+
+    fn corrupt_save() -> anyhow::Result<()> {
+        Err(SaveCorrupt.into())
+    }
+
+    fn recover_save(_: SaveCorrupt, commands: &mut Commands) {
+        commands.add(|world: &mut World| world.resource_mut::<RecoveredSaves>().0 += 1);
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new());
+        app
+    }
+
+    #[test]
+    fn test_one_error_system() {
+        let mut app = app();
+        app.add_systems(Update, alternate_output.anyhow_alert());
+        app.assert_no_alerts();
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    #[test]
+    fn test_alert_test_ext() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                failed_loading_settings.anyhow_alert(),
+                corrupt_save.warn_alert(),
+            )
+                .chain(),
+        );
+        app.update();
+        app.update();
+        assert_eq!(app.alert_count(), 4);
+        assert_eq!(
+            app.alert_messages(),
+            vec![
+                "loading settings",
+                "save file is corrupt",
+                "loading settings",
+                "save file is corrupt",
+            ]
+        );
+        app.assert_alerted("corrupt");
+        app.clear_alerts();
+        app.assert_no_alerts();
+        assert_eq!(raised_count(app.world_mut()), 0);
+    }
+
+    #[test]
+    fn test_value_forwarding_system() {
+        let mut app = app();
+        app.init_resource::<ReceivedValues>();
+        app.add_systems(
+            Update,
+            alternate_output_value.anyhow_alert().pipe(record_value),
+        );
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        let received = app.world().resource::<ReceivedValues>();
+        assert_eq!(received.0, vec![Some(1), None, Some(3)]);
+    }
+
+    #[test]
+    fn test_input_forwarding_system() {
+        let mut app = app();
+        app.init_resource::<ReceivedValues>();
+        app.add_systems(
+            Update,
+            count_frames
+                .pipe(reject_even.anyhow_alert())
+                .pipe(record_value),
+        );
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        let received = app.world().resource::<ReceivedValues>();
+        assert_eq!(received.0, vec![Some(1), None, Some(3)]);
+    }
+
+    #[test]
+    fn test_input_systems() {
+        let mut app = app();
+        let reject_even_batch = || reject_even.map(|result| result.map_err(|error| vec![error]));
+        app.add_systems(
+            Update,
+            (
+                count_frames.pipe(reject_even).anyhow_alert(),
+                count_frames.pipe(reject_even.anyhow_alert()),
+                count_frames.pipe(reject_even).warn_alert(),
+                count_frames.pipe(reject_even_batch()).anyhow_alerts(),
+                count_frames.pipe(reject_even_batch().info_alerts()),
+            ),
+        );
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        let mut query = app.world_mut().query::<(&Severity, &AlertCount)>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|(severity, count)| (*severity, count.0))
+            .collect::<Vec<_>>();
+        alerts.sort();
+        // the frame count is even
+        assert_eq!(
+            alerts,
+            vec![
+                (Severity::Info, 1),
+                (Severity::Warning, 1),
+                (Severity::Error, 3)
+            ]
+        );
+        app.update();
+        let mut query = app.world_mut().query::<&AlertCount>();
+        assert_eq!(
+            query.iter(app.world()).map(|count| count.0).sum::<usize>(),
+            5
+        );
+        app.update();
+        assert_eq!(
+            query.iter(app.world()).map(|count| count.0).sum::<usize>(),
+            10
+        );
+    }
+
+    fn missing_save() -> Result<(), String> {
+        Err(format!("save {} is missing", 3))
+    }
+
+    fn missing_saves() -> ResultVec<(), String> {
+        Err(vec!["save 1 is missing".into(), "save 2 is missing".into()])
+    }
+
+    #[test]
+    fn test_string_errors() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (missing_save.anyhow_alert(), missing_saves.warn_alerts()),
+        );
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, &Severity), With<Alert>>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|(message, severity)| (message.0.clone(), *severity))
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                ("save 1 is missing".to_string(), Severity::Warning),
+                ("save 2 is missing".to_string(), Severity::Warning),
+                ("save 3 is missing".to_string(), Severity::Error),
+            ]
+        );
+    }
+
+    type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+    fn parse_boxed() -> Result<(), BoxError> {
+        "not a number".parse::<u32>()?;
+        Ok(())
+    }
+
+    fn parse_all_boxed() -> ResultVec<(), BoxError> {
+        Err(vec![TestError.into(), "missing field".into()])
+    }
+
+    #[test]
+    fn test_boxed_errors() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (parse_boxed.anyhow_alert(), parse_all_boxed.anyhow_alerts()),
+        );
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&AlertMessage, With<Alert>>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|message| message.0.clone())
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                "invalid digit found in string".to_string(),
+                "missing field".to_string(),
+                "testing!".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_value_discarding_system() {
+        let mut app = app();
+        app.add_systems(Update, alternate_output_value.anyhow_alert());
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+    }
+
+    #[test]
+    fn test_default_substituting_system() {
+        let mut app = app();
+        app.init_resource::<ReceivedDefaults>();
+        app.add_systems(
+            Update,
+            alternate_output_value
+                .anyhow_alert_default()
+                .pipe(record_default),
+        );
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        let received = app.world().resource::<ReceivedDefaults>();
+        assert_eq!(received.0, vec![1, 0, 3]);
+    }
+
+    #[test]
+    fn test_option_error_system() {
+        let mut app = app();
+        app.add_systems(Update, alternate_option_output.option_alert());
+        app.assert_no_alerts();
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    #[test]
+    fn test_error_collecting_system() {
+        let mut app = app();
         app.add_systems(Update, alternate_output_many_errors.anyhow_alerts());
+        app.assert_no_alerts();
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    fn process_ids(mut counter: Local<u32>) -> ResultVec<Vec<u32>, TestError> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            Ok(vec![*counter, *counter + 10])
+        } else {
+            Err(vec![TestError, TestError])
+        }
+    }
+
+    #[derive(Default, Resource)]
+    struct ProcessedIds(Vec<Option<Vec<u32>>>);
+
+    fn record_ids(In(ids): In<Option<Vec<u32>>>, mut processed: ResMut<ProcessedIds>) {
+        processed.0.push(ids);
+    }
+
+    #[test]
+    fn test_error_collecting_value_system() {
+        let mut app = app();
+        app.init_resource::<ProcessedIds>();
+        app.add_systems(Update, process_ids.anyhow_alerts().pipe(record_ids));
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+        let processed = app.world().resource::<ProcessedIds>();
+        assert_eq!(processed.0, vec![Some(vec![1, 11]), None]);
+    }
+
+    fn process_some_ids(mut counter: Local<u32>) -> Partial<Vec<u32>, TestError> {
+        *counter += 1;
+        let mut partial = Partial::new(vec![*counter]);
+        if *counter % 2 == 0 {
+            partial.errors.push(TestError);
+        }
+        partial
+    }
+
+    fn record_some_ids(In(ids): In<Vec<u32>>, mut processed: ResMut<ProcessedIds>) {
+        processed.0.push(Some(ids));
+    }
+
+    #[test]
+    fn test_partial_alerts() {
+        let mut app = app();
+        app.init_resource::<ProcessedIds>();
+        app.add_systems(
+            Update,
+            process_some_ids.partial_alerts().pipe(record_some_ids),
+        );
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        let processed = app.world().resource::<ProcessedIds>();
+        assert_eq!(processed.0, vec![Some(vec![1]), Some(vec![2])]);
+    }
+
+    fn alternate_error_list(mut counter: Local<usize>) -> Vec<TestError> {
+        *counter += 1;
+        if *counter % 2 == 1 {
+            vec![]
+        } else {
+            vec![TestError]
+        }
+    }
+
+    #[test]
+    fn test_error_list_system() {
+        let mut app = app();
+        app.add_systems(Update, alternate_error_list.alert_all());
+        app.assert_no_alerts();
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    #[derive(Debug, Error)]
+    #[error("row {0} is invalid")]
+    struct InvalidRow(u32);
+
+    fn validate_rows() -> Vec<Result<(), InvalidRow>> {
+        vec![Ok(()), Err(InvalidRow(2)), Ok(()), Err(InvalidRow(4))]
+    }
+
+    fn valid_rows() -> Vec<Result<u32, InvalidRow>> {
+        vec![Ok(1), Err(InvalidRow(2)), Ok(3)]
+    }
+
+    fn record_rows(In(rows): In<Vec<u32>>, mut processed: ResMut<ProcessedIds>) {
+        processed.0.push(Some(rows));
+    }
+
+    #[test]
+    fn test_result_list_system() {
+        let mut app = app();
+        app.init_resource::<ProcessedIds>();
+        app.add_systems(
+            Update,
+            (
+                validate_rows.alert_errs(),
+                valid_rows.alert_errs().pipe(record_rows),
+            ),
+        );
+        app.update();
+        let mut query = app.world_mut().query::<(&AlertMessage, &AlertCount)>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|(message, count)| (message.0.clone(), count.0))
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                ("row 2 is invalid".to_string(), 2),
+                ("row 4 is invalid".to_string(), 1),
+            ]
+        );
+        let processed = app.world().resource::<ProcessedIds>();
+        assert_eq!(processed.0, vec![Some(vec![1, 3])]);
+    }
+
+    fn import_rows(In(rows): In<u32>) -> ResultVec<(), InvalidRow> {
+        Err((1..=rows).map(InvalidRow).collect())
+    }
+
+    #[test]
+    fn test_summarized_alerts() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                (|| 50).pipe(import_rows.anyhow_alerts_summarized(10)),
+                (|| 2).pipe(import_rows.warn_alerts()),
+            )
+                .chain(),
+        );
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&AlertMessage, With<Alert>>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|message| message.0.clone())
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                "50 errors: first is 'row 1 is invalid' (see log)".to_string(),
+                "row 1 is invalid".to_string(),
+                "row 2 is invalid".to_string(),
+            ]
+        );
+        let history = app.world().resource::<AlertHistory>();
+        assert_eq!(history.len(), 52);
+        assert!(history
+            .iter()
+            .all(|entry| !entry.message.starts_with("50 errors")));
+    }
+
+    #[test]
+    fn test_summarized_single_alert() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                (|| 1).pipe(import_rows.anyhow_alerts_summarized(0)),
+                (|| 2).pipe(import_rows.anyhow_alerts_summarized(0)),
+            ),
+        );
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&AlertMessage, With<Alert>>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|message| message.0.clone())
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                "2 errors: first is 'row 1 is invalid' (see log)".to_string(),
+                "row 1 is invalid".to_string(),
+            ]
+        );
+    }
+
+    #[derive(Debug, Error)]
+    #[error("save failed")]
+    struct CodedSaveError;
+
+    impl HasAlertMeta for CodedSaveError {
+        fn alert_meta(&self) -> AlertMeta {
+            AlertMeta::code("E1042").with_help_url("https://example.com/wiki/E1042")
+        }
+    }
+
+    fn coded_save() -> Result<(), CodedSaveError> {
+        Err(CodedSaveError)
+    }
+
+    #[test]
+    fn test_alert_meta() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                coded_save.anyhow_alert_coded(),
+                disk_full.anyhow_alert_meta(|_| AlertMeta::code("E7")),
+            ),
+        );
+        app.update();
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(texts, vec!["E1042: save failed", "E7: disk full"]);
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, &AlertMeta), With<Alert>>();
+        let (_, meta) = query
+            .iter(app.world())
+            .find(|(message, _)| message.0.starts_with("E1042"))
+            .unwrap();
+        assert_eq!(
+            meta.help_url.as_deref(),
+            Some("https://example.com/wiki/E1042")
+        );
+        let history = app.world().resource::<AlertHistory>();
+        let codes = history
+            .iter()
+            .map(|entry| entry.meta.as_ref().and_then(|meta| meta.code.clone()))
+            .collect::<Vec<_>>();
+        assert!(codes.contains(&Some("E1042".to_string())));
+        assert!(codes.contains(&Some("E7".to_string())));
+    }
+
+    impl LocalizableError for InvalidRow {
+        fn l10n_key(&self) -> &str {
+            "invalid-row"
+        }
+
+        fn args(&self) -> Vec<(&'static str, String)> {
+            vec![("row", self.0.to_string())]
+        }
+    }
+
+    fn invalid_row() -> Result<(), InvalidRow> {
+        Err(InvalidRow(7))
+    }
+
+    #[test]
+    fn test_localized_alerts() {
+        let mut app = app();
+        app.insert_resource(AlertLocalizer::new(|key| match key {
+            "disk full" => Some("disque plein".into()),
+            "invalid-row" => Some("la ligne {row} est invalide".into()),
+            _ => None,
+        }));
+        app.add_systems(
+            Update,
+            (
+                disk_full_once.anyhow_alert(),
+                invalid_row.anyhow_alert_localized(),
+                corrupt_save.anyhow_alert(),
+            ),
+        );
+        app.update();
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(
+            texts,
+            vec![
+                "disque plein",
+                "la ligne 7 est invalide",
+                "save file is corrupt"
+            ]
+        );
+        let history = app.world().resource::<AlertHistory>();
+        let mut messages = history
+            .iter()
+            .map(|entry| entry.message.as_str())
+            .collect::<Vec<_>>();
+        messages.sort();
+        assert_eq!(
+            messages,
+            vec!["disk full", "row 7 is invalid", "save file is corrupt"]
+        );
+    }
+
+    fn validate_meshes() -> ResultVec<(), String> {
+        let missing = std::iter::repeat("missing texture".to_string()).take(40);
+        let bad_uvs = std::iter::repeat("bad UV".to_string()).take(3);
+        Err(bad_uvs
+            .chain(missing)
+            .chain(["no mesh".to_string()])
+            .collect())
+    }
+
+    #[test]
+    fn test_grouped_alerts() {
+        let mut app = app();
+        app.insert_resource(AlertConfig {
+            dedup: false,
+            ..default()
+        });
+        app.add_systems(Update, validate_meshes.anyhow_alerts_grouped());
+        app.update();
+        assert_eq!(
+            stacked_messages(app.world_mut()),
+            vec!["missing texture", "bad UV", "no mesh"]
+        );
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(
+            texts,
+            vec!["bad UV (x3)", "missing texture (x40)", "no mesh"]
+        );
+        let history = app.world().resource::<AlertHistory>();
+        let counts = history
+            .iter()
+            .map(|entry| (entry.message.as_str(), entry.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            counts,
+            vec![("missing texture", 40), ("bad UV", 3), ("no mesh", 1)]
+        );
+        assert_eq!(app.alert_count(), 44);
+        let stats = app.world().resource::<ErrorStats>();
+        assert_eq!(stats.get("missing texture").unwrap().count, 40);
+        assert_eq!(stats.total(), 44);
+    }
+
+    #[test]
+    fn test_anyhow_error_collecting_system() {
+        let mut app = app();
+        app.add_systems(Update, alternate_output_many_anyhow_errors.anyhow_alerts());
+        app.assert_no_alerts();
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+        app.update();
+        assert_eq!(app.alert_count(), 4);
+    }
+
+    #[test]
+    fn test_display_only_error_collecting_system() {
+        let mut app = app();
+        app.add_systems(Update, display_only_errors.anyhow_alerts());
+        app.update();
+        assert_eq!(app.alert_count(), 1);
+    }
+
+    #[test]
+    fn test_mapped_error_system() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            alternate_output.anyhow_alert_map(|error| format!("mapped {error}")),
+        );
+        app.update();
+        assert!(alert_texts(app.world_mut()).is_empty());
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["mapped testing!"]);
+    }
+
+    #[test]
+    fn test_context_error_system() {
+        let mut app = app();
+        app.add_systems(Update, disk_full.anyhow_alert_context("save-system"));
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["save-system: disk full"]);
+    }
+
+    #[test]
+    fn test_context_error_collecting_system() {
+        let mut app = app();
+        app.add_systems(Update, disk_full_many.anyhow_alerts_context("save-system"));
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["save-system: disk full"]);
+    }
+
+    #[test]
+    fn test_severity_error_system() {
+        for severity in [Severity::Info, Severity::Warning, Severity::Error] {
+            let mut app = app();
+            app.add_systems(Update, disk_full.anyhow_alert_with_severity(severity));
+            app.update();
+            let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+            let severities = query.iter(app.world()).copied().collect::<Vec<_>>();
+            assert_eq!(severities, vec![severity]);
+        }
+    }
+
+    #[test]
+    fn test_default_severity() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (disk_full.anyhow_alert(), disk_full_many.anyhow_alerts()),
+        );
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+        let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+        assert!(query
+            .iter(app.world())
+            .all(|severity| *severity == Severity::Error));
+    }
+
+    #[test]
+    fn test_severity_shorthands() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert(),
+                disk_full.warn_alert(),
+                disk_full.info_alert(),
+            ),
+        );
+        app.update();
+        let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+        let mut severities = query.iter(app.world()).copied().collect::<Vec<_>>();
+        severities.sort();
+        assert_eq!(
+            severities,
+            vec![Severity::Info, Severity::Warning, Severity::Error]
+        );
+    }
+
+    #[test]
+    fn test_severity_collecting_shorthands() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                disk_full_many.anyhow_alerts(),
+                disk_full_many.warn_alerts(),
+                disk_full_many.info_alerts(),
+            ),
+        );
+        app.update();
+        let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+        let mut severities = query.iter(app.world()).copied().collect::<Vec<_>>();
+        severities.sort();
+        assert_eq!(
+            severities,
+            vec![Severity::Info, Severity::Warning, Severity::Error]
+        );
+    }
+
+    fn ten_errors(mut counter: Local<usize>) -> Result<(), DiskFullError> {
+        *counter += 1;
+        if *counter <= 10 {
+            Err(DiskFullError)
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_deduplicated_alerts() {
+        let mut app = app();
+        app.add_systems(Update, ten_errors.anyhow_alert());
+        for _ in 0..10 {
+            app.update();
+        }
+        let mut query = app.world_mut().query::<(&Alert, &AlertCount)>();
+        let counts = query
+            .iter(app.world())
+            .map(|(_, count)| *count)
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![AlertCount(10)]);
+        assert_eq!(alert_texts(app.world_mut()), vec!["disk full (x10)"]);
+    }
+
+    #[test]
+    fn test_deduplication_opt_out() {
+        let mut app = app();
+        let mut config = app.world_mut().resource_mut::<AlertConfig>();
+        config.dedup = false;
+        config.max_visible = None;
+        app.add_systems(Update, ten_errors.anyhow_alert());
+        for _ in 0..10 {
+            app.update();
+        }
+        let mut query = app.world_mut().query::<(&Alert, &AlertCount)>();
+        assert_eq!(query.iter(app.world()).count(), 10);
+        assert!(query
+            .iter(app.world())
+            .all(|(_, count)| *count == AlertCount(1)));
+    }
+
+    #[test]
+    fn test_deduplication_forgets_despawned_alerts() {
+        let mut app = app();
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let first = query.single(app.world());
+        app.world_mut().entity_mut(first).despawn_recursive();
+        app.update();
+        let second = query.single(app.world());
+        assert_ne!(first, second);
+        assert_eq!(raised_count(app.world_mut()), 1);
+    }
+
+    #[test]
+    fn test_fixed_update_burst() {
+        let mut app = app();
+        app.insert_resource(Time::<Fixed>::from_duration(Duration::from_millis(10)));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.add_systems(FixedUpdate, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        let mut query = app.world_mut().query::<(&Alert, &AlertCount)>();
+        let counts = query
+            .iter(app.world())
+            .map(|(_, count)| *count)
+            .collect::<Vec<_>>();
+        assert_eq!(counts, vec![AlertCount(10)]);
+        assert_eq!(app.alert_count(), 10);
+    }
+
+    #[test]
+    fn test_throttled_alerts() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.add_systems(
+            Update,
+            disk_full.anyhow_alert_throttle(Duration::from_secs(1)),
+        );
+        for _ in 0..20 {
+            app.update();
+        }
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    fn corrupt_save_file(mut counter: Local<usize>) -> anyhow::Result<()> {
+        *counter += 1;
+        match *counter {
+            1..=5 => Err(anyhow::anyhow!("save file corrupt")),
+            6 => Err(anyhow::anyhow!("settings file corrupt")),
+            _ => Ok(()),
+        }
+    }
+
+    #[test]
+    fn test_alert_once() {
+        let mut app = app();
+        app.add_systems(Update, corrupt_save_file.anyhow_alert_once());
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(app.alert_count(), 2);
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(texts, vec!["save file corrupt", "settings file corrupt"]);
+    }
+
+    /// A tracing layer that records the messages of events logged by this crate.
+    #[derive(Clone, Default)]
+    struct CapturedLogs(Arc<Mutex<Vec<(Level, String)>>>);
+
+    impl<S: Subscriber> Layer<S> for CapturedLogs {
+        fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+            struct MessageVisitor(String);
+
+            impl Visit for MessageVisitor {
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    if field.name() == "message" {
+                        self.0 = format!("{value:?}");
+                    }
+                }
+            }
+
+            if event.metadata().target() == LOG_TARGET {
+                let mut visitor = MessageVisitor(String::new());
+                event.record(&mut visitor);
+                let level = *event.metadata().level();
+                self.0.lock().unwrap().push((level, visitor.0));
+            }
+        }
+    }
+
+    fn failed_loading_settings() -> anyhow::Result<()> {
+        Err(anyhow::Error::new(DiskFullError).context("loading settings"))
+    }
+
+    fn failed_loading_all_settings() -> ResultVec<(), anyhow::Error> {
+        Err(vec![
+            anyhow::Error::new(DiskFullError).context("loading settings"),
+            anyhow::anyhow!("loading keybinds"),
+        ])
+    }
+
+    fn capture_logs(app: &mut App) -> Vec<(Level, String)> {
+        // run on this thread so the scoped subscriber sees the events
+        app.edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+        let logs = CapturedLogs::default();
+        let subscriber = tracing_subscriber::registry().with(logs.clone());
+        bevy::utils::tracing::subscriber::with_default(subscriber, || app.update());
+        let captured = logs.0.lock().unwrap().clone();
+        captured
+    }
+
+    #[test]
+    fn test_logged_error_system() {
+        let mut app = app();
+        app.add_systems(Update, failed_loading_settings.anyhow_alert_log());
+        let logs = capture_logs(&mut app);
+        assert_eq!(
+            logs,
+            vec![(Level::ERROR, "loading settings: disk full".to_string())]
+        );
+        assert_eq!(alert_texts(app.world_mut()), vec!["loading settings"]);
+    }
+
+    #[test]
+    fn test_logged_error_collecting_system() {
+        let mut app = app();
+        app.add_systems(Update, failed_loading_all_settings.anyhow_alerts_log());
+        let logs = capture_logs(&mut app);
+        assert_eq!(
+            logs,
+            vec![
+                (Level::ERROR, "loading settings: disk full".to_string()),
+                (Level::ERROR, "loading keybinds".to_string()),
+            ]
+        );
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    #[test]
+    fn test_missing_plugin_fallback() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, (disk_full.anyhow_alert(), disk_full.warn_alert()));
+        let mut logs = capture_logs(&mut app);
+        logs.extend(capture_logs(&mut app));
+        let warnings = logs
+            .iter()
+            .filter(|(_, message)| message.contains("`AlertsPlugin` was not added"))
+            .count();
+        assert_eq!(warnings, 1);
+        let messages = logs
+            .iter()
+            .filter(|(_, message)| message == "disk full")
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 4);
+        let mut query = app.world_mut().query::<&Alert>();
+        assert_eq!(query.iter(app.world()).count(), 0);
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordingBackend(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+    impl AlertBackend for RecordingBackend {
+        fn handle(&self, alerts: Vec<RaisedAlert>, _: &mut Commands) {
+            let mut messages = self.0.lock().unwrap();
+            messages.extend(alerts.into_iter().map(|alert| alert.message.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_custom_backend() {
+        let backend = RecordingBackend::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().with_backend(backend.clone()));
+        app.add_systems(Update, (disk_full.anyhow_alert(), five_errors));
+        app.update();
+        let messages = backend.0.lock().unwrap().clone();
+        assert_eq!(messages.len(), 6);
+        assert_eq!(messages.iter().filter(|m| *m == "disk full").count(), 1);
+        assert_eq!(app.world().resource::<AlertHistory>().len(), 6);
         let mut query = app.world_mut().query::<&Alert>();
         assert_eq!(query.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_alert_sender() {
+        let mut app = app();
+        let sender = app.world().resource::<AlertSender>().clone();
+        let handle = app.world().resource::<ErrorSink>().handle();
+        std::thread::spawn(move || {
+            sender.send("download failed");
+            sender.send_with_severity("download slow", Severity::Warning);
+            handle.send_message(AlertMessage("texture too large".into()), Severity::Info);
+        })
+        .join()
+        .unwrap();
+        app.update();
+        assert_eq!(app.alert_count(), 3);
+        let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+        let mut severities = query.iter(app.world()).copied().collect::<Vec<_>>();
+        severities.sort();
+        assert_eq!(
+            severities,
+            vec![Severity::Info, Severity::Warning, Severity::Error]
+        );
+    }
+
+    #[derive(Component)]
+    struct Checkpoint;
+
+    fn save_checkpoint(world: &mut World) -> anyhow::Result<()> {
+        world.spawn(Checkpoint);
+        world.commands().spawn(Checkpoint);
+        anyhow::bail!("disk full")
+    }
+
+    #[test]
+    fn test_exclusive_alerts() {
+        let mut app = app();
+        app.add_systems(Update, save_checkpoint.anyhow_alert());
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&AlertMessage, With<Alert>>();
+        let alerts = query
+            .iter(app.world())
+            .map(|message| message.0.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(alerts, vec!["disk full".to_string()]);
+        let mut checkpoints = app.world_mut().query::<&Checkpoint>();
+        assert_eq!(checkpoints.iter(app.world()).count(), 2);
+    }
+
+    #[test]
+    fn test_entity_alerts() {
+        let mut app = app();
+        let npc = app.world_mut().spawn(Name::new("NPC 42")).id();
+        let unnamed = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .entity(npc)
+            .alert("no pathfinding agent");
+        app.world_mut().commands().entity(unnamed).alert("stuck");
+        app.world_mut().flush();
+        // the alerts stay valid after their source despawns
+        app.world_mut().despawn(npc);
+        app.update();
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, &AlertSource), With<Alert>>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|(message, source)| (message.0.clone(), source.0))
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                ("no pathfinding agent (NPC 42)".to_string(), npc),
+                ("stuck".to_string(), unnamed),
+            ]
+        );
+    }
+
+    struct ValidateSave;
+
+    impl bevy::ecs::world::Command for ValidateSave {
+        fn apply(self, world: &mut World) {
+            world.alert_error(&TestError);
+        }
+    }
+
+    #[test]
+    fn test_world_alerts() {
+        let mut app = app();
+        app.add_systems(Update, |world: &mut World| {
+            world.alert_with(Severity::Warning, "low memory")
+        });
+        app.world_mut().commands().add(ValidateSave);
+        // raised before the app has run, so before the alert root exists
+        app.world_mut().flush();
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, &Severity), With<Alert>>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|(message, severity)| (message.0.clone(), *severity))
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(
+            alerts,
+            vec![
+                ("low memory".to_string(), Severity::Warning),
+                ("testing!".to_string(), Severity::Error),
+            ]
+        );
+    }
+
+    fn reject_level(In(level): In<u32>) -> Result<(), TestError> {
+        if level < 10 {
+            Ok(())
+        } else {
+            Err(TestError)
+        }
+    }
+
+    #[test]
+    fn test_one_shot_alerts() {
+        let mut app = app();
+        let failing = app.world_mut().register_system(|| Err::<(), _>(TestError));
+        let checked = app.world_mut().register_system(reject_level);
+        app.world_mut().commands().run_system_alert(failing);
+        app.world_mut()
+            .commands()
+            .run_system_with_input_alert(checked, 3);
+        app.world_mut().flush();
+        app.world_mut().run_system_with_input_alert(checked, 12);
+        // systems that can't be run raise an alert too
+        app.world_mut().remove_system(failing).unwrap();
+        app.world_mut().run_system_alert(failing);
+        app.update();
+        let mut query = app.world_mut().query::<(&AlertMessage, &AlertCount)>();
+        let mut alerts = query
+            .iter(app.world())
+            .map(|(message, count)| (message.0.clone(), count.0))
+            .collect::<Vec<_>>();
+        alerts.sort();
+        assert_eq!(alerts.len(), 2);
+        assert!(alerts[0].0.ends_with("was not registered"));
+        assert_eq!(alerts[1], ("testing!".to_string(), 2));
+    }
+
+    #[derive(Component)]
+    struct LoadLevel(bevy::tasks::Task<Result<u32, TestError>>);
+
+    impl FallibleTask for LoadLevel {
+        type Output = u32;
+        type Error = TestError;
+
+        fn task_mut(&mut self) -> &mut bevy::tasks::Task<Result<u32, TestError>> {
+            &mut self.0
+        }
+    }
+
+    #[test]
+    fn test_task_alerts() {
+        use bevy::tasks::{futures_lite::future, AsyncComputeTaskPool};
+
+        let mut app = app();
+        app.add_plugins(TaskAlertPlugin::<LoadLevel>::default());
+        let pool = AsyncComputeTaskPool::get();
+        let loaded = app
+            .world_mut()
+            .spawn(LoadLevel(pool.spawn(async { Ok(7) })))
+            .id();
+        let failed = app
+            .world_mut()
+            .spawn(LoadLevel(pool.spawn(async { Err(TestError) })))
+            .id();
+        // a task whose entity is despawned mid-flight is cancelled without an alert
+        let abandoned = app
+            .world_mut()
+            .spawn(LoadLevel(pool.spawn(future::pending())))
+            .id();
+        app.world_mut().despawn(abandoned);
+        let mut completed = Vec::new();
+        for _ in 0..100 {
+            app.update();
+            completed.extend(
+                app.world_mut()
+                    .resource_mut::<Events<TaskCompleted<LoadLevel>>>()
+                    .drain()
+                    .map(|event| (event.entity, event.output)),
+            );
+            let mut tasks = app.world_mut().query::<&LoadLevel>();
+            if tasks.iter(app.world()).next().is_none() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(completed, vec![(loaded, 7)]);
+        // the alert is raised in the frame after the task is polled at most
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, &AlertSource), With<Alert>>();
+        let alerts = query
+            .iter(app.world())
+            .map(|(message, source)| (message.0.clone(), source.0))
+            .collect::<Vec<_>>();
+        assert_eq!(alerts, vec![("testing!".to_string(), failed)]);
+    }
+
+    #[test]
+    fn test_triggered_alerts() {
+        let mut app = app();
+        let player = app.world_mut().spawn_empty().id();
+        app.world_mut()
+            .commands()
+            .trigger(ErrorRaised::new("disk full"));
+        app.world_mut()
+            .commands()
+            .entity(player)
+            .trigger_alert("player stuck");
+        app.world_mut().flush();
+        app.update();
+        assert_eq!(app.alert_count(), 2);
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, Option<&AlertSource>), With<Alert>>();
+        let mut sources = query
+            .iter(app.world())
+            .map(|(message, source)| (message.0.clone(), source.copied()))
+            .collect::<Vec<_>>();
+        sources.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            sources,
+            vec![
+                ("disk full".to_string(), None),
+                ("player stuck".to_string(), Some(AlertSource(player))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paused_alerts() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().paused = true;
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 0);
+        let queue = app.world().resource::<AlertQueue>();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.iter().next().unwrap().count, 2);
+        app.world_mut().resource_mut::<AlertConfig>().paused = false;
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 3);
+        assert!(app.world().resource::<AlertQueue>().is_empty());
+    }
+
+    #[test]
+    fn test_muted_alerts() {
+        let reports = Arc::new(Mutex::new(0));
+        let reported = reports.clone();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().on_alert(move |_: &AlertReport| {
+            *reported.lock().unwrap() += 1;
+        }));
+        app.world_mut().resource_mut::<AlertConfig>().muted = true;
+        app.add_systems(Update, push_disk_full.run_if(run_once()));
+        app.update();
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 0);
+        assert_eq!(app.world().resource::<AlertHistory>().len(), 3);
+        assert_eq!(*reports.lock().unwrap(), 3);
+        assert_eq!(
+            *app.world().resource::<SuppressedAlerts>(),
+            SuppressedAlerts(3)
+        );
+        app.world_mut().resource_mut::<AlertConfig>().muted = false;
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["3 alerts suppressed"]);
+        assert_eq!(
+            *app.world().resource::<SuppressedAlerts>(),
+            SuppressedAlerts(0)
+        );
+    }
+
+    #[test]
+    fn test_alert_theme() {
+        let theme = AlertTheme {
+            warning_background: Color::srgb(1., 0.5, 0.),
+            text_color: Color::WHITE,
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().with_theme(theme));
+        app.add_systems(Update, (disk_full.anyhow_alert(), disk_full.warn_alert()));
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&Severity, &BackgroundColor), With<Alert>>();
+        let mut backgrounds = query
+            .iter(app.world())
+            .map(|(severity, background)| (*severity, background.0))
+            .collect::<Vec<_>>();
+        backgrounds.sort_by_key(|(severity, _)| *severity);
+        assert_eq!(
+            backgrounds,
+            vec![
+                (Severity::Warning, Color::srgb(1., 0.5, 0.)),
+                (Severity::Error, AlertTheme::default().error_background),
+            ]
+        );
+        let mut texts = app.world_mut().query::<(&Name, &Text)>();
+        let colors = texts
+            .iter(app.world())
+            .filter(|(name, _)| name.as_str() == "Alert Text")
+            .map(|(_, text)| text.sections[0].style.color)
+            .collect::<Vec<_>>();
+        assert_eq!(colors, vec![Color::WHITE; 2]);
+    }
+
+    fn wrapped_test_error() -> anyhow::Result<()> {
+        Err(TestError.into())
+    }
+
+    #[test]
+    fn test_alert_style_registry() {
+        let mut app = app();
+        let orange = Color::srgb(1., 0.5, 0.);
+        let red = Color::srgb(1., 0., 0.);
+        app.world_mut()
+            .resource_mut::<AlertStyleRegistry>()
+            .register::<TestError>(AlertStyle {
+                background: Some(orange),
+                ..Default::default()
+            })
+            .register::<DiskFullError>(AlertStyle {
+                background: Some(red),
+                ..Default::default()
+            });
+        app.add_systems(
+            Update,
+            (wrapped_test_error.anyhow_alert(), disk_full.anyhow_alert()),
+        );
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&AlertMessage, &BackgroundColor), With<Alert>>();
+        let mut backgrounds = query
+            .iter(app.world())
+            .map(|(message, background)| (message.0.clone(), background.0))
+            .collect::<Vec<_>>();
+        backgrounds.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            backgrounds,
+            vec![
+                ("disk full".to_string(), red),
+                ("testing!".to_string(), orange)
+            ]
+        );
+    }
+
+    #[derive(Component)]
+    struct CustomLine;
+
+    #[test]
+    fn test_alert_builder() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().with_builder(|body, message| {
+            body.spawn((CustomLine, Name::new(message.0.clone())));
+            body.spawn((CustomLine, Name::new("second line")));
+        }));
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let alert = query.single(app.world());
+        let mut lines = app
+            .world_mut()
+            .query_filtered::<(&Name, &Parent), With<CustomLine>>();
+        let lines = lines
+            .iter(app.world())
+            .map(|(name, parent)| (name.as_str().to_string(), parent.get()))
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].0, "disk full");
+        assert_eq!(lines[1].0, "second line");
+        let body = lines[0].1;
+        assert_eq!(app.world().get::<Parent>(body).unwrap().get(), alert);
+        assert!(alert_texts(app.world_mut()).is_empty());
+    }
+
+    #[test]
+    fn test_alert_anchor() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertTheme>().anchor = AlertAnchor::BottomLeft;
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let mut roots = app
+            .world_mut()
+            .query_filtered::<&Style, With<AlertUiRoot>>();
+        let style = roots.single(app.world());
+        assert_eq!(style.left, Val::Px(24.));
+        assert_eq!(style.right, Val::Auto);
+        assert_eq!(style.bottom, Val::Px(24.));
+        assert_eq!(style.top, Val::Auto);
+        assert_eq!(style.justify_content, JustifyContent::FlexEnd);
+        assert_eq!(style.align_items, AlignItems::FlexStart);
+        let mut theme = app.world_mut().resource_mut::<AlertTheme>();
+        theme.anchor = AlertAnchor::TopCenter;
+        theme.offset = Vec2::new(-16., 8.);
+        app.update();
+        let style = roots.single(app.world());
+        assert_eq!(style.margin.left, Val::Px(-16.));
+        assert_eq!(style.top, Val::Px(8.));
+        assert_eq!(style.bottom, Val::Auto);
+        assert_eq!(style.justify_content, JustifyContent::FlexStart);
+        assert_eq!(style.align_items, AlignItems::Center);
+    }
+
+    fn alert_alphas(world: &mut World) -> Vec<f32> {
+        let mut query = world.query_filtered::<&BackgroundColor, With<Alert>>();
+        query
+            .iter(world)
+            .map(|background| background.0.alpha())
+            .collect()
+    }
+
+    #[test]
+    fn test_alert_animation() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertTheme>().animation = AlertAnimation {
+            enter: AlertEffect::Fade,
+            exit: AlertEffect::Fade,
+            duration: Duration::from_secs(1),
+        };
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_millis(500));
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        let mut alphas = Vec::new();
+        for _ in 0..11 {
+            app.update();
+            alphas.push(alert_alphas(app.world_mut()));
+        }
+        // the ttl only counts down once the alert has faded in
+        let shown = [1.0; 3].map(|alpha| vec![alpha]);
+        let entering = [0.0, 0.25, 0.5, 0.75].map(|alpha| vec![alpha]);
+        let exiting = [0.75, 0.5, 0.25].map(|alpha| vec![alpha]);
+        let expected = [&entering[..], &shown, &exiting, &[vec![]]].concat();
+        assert_eq!(alphas, expected);
+    }
+
+    fn dismissed_events(world: &World) -> Vec<AlertDismissed> {
+        let events = world.resource::<Events<AlertDismissed>>();
+        events.get_reader().read(events).cloned().collect()
+    }
+
+    #[test]
+    fn test_headless_alerts() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AlertsPlugin::headless()));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_secs(1));
+        app.add_systems(
+            Update,
+            (|mut runs: Local<u32>| {
+                *runs += 1;
+                match *runs {
+                    1 | 2 => fail_with("save file is corrupt")(),
+                    _ => Ok(()),
+                }
+            })
+            .anyhow_alert(),
+        );
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<MockAlerts>().messages,
+            vec!["save file is corrupt", "save file is corrupt"]
+        );
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<(&AlertCount, Has<Children>), (With<Alert>, Without<AlertUi>)>();
+        let alerts = alerts.iter(app.world()).collect::<Vec<_>>();
+        assert_eq!(alerts, vec![(&AlertCount(2), false)]);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(raised_count(app.world_mut()), 0);
+        let reasons = dismissed_events(app.world())
+            .into_iter()
+            .map(|event| event.reason)
+            .collect::<Vec<_>>();
+        assert_eq!(reasons, vec![DismissReason::Expired]);
+    }
+
+    /// An [`AlertBackend`] keeping the messages of the raised alerts.
+    #[derive(Clone, Default)]
+    struct MessagesBackend(Arc<std::sync::Mutex<Vec<Arc<str>>>>);
+
+    impl AlertBackend for MessagesBackend {
+        fn handle(&self, alerts: Vec<RaisedAlert>, _: &mut Commands) {
+            let messages = alerts.into_iter().map(|alert| alert.message);
+            self.0.lock().unwrap().extend(messages);
+        }
+    }
+
+    #[test]
+    fn test_repeated_alerts_share_message() {
+        let backend = MessagesBackend::default();
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AlertsPlugin::new().with_backend(backend.clone()),
+        ));
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        let messages = backend.0.lock().unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(&*messages[0], "disk full");
+        assert!(Arc::ptr_eq(&messages[0], &messages[1]));
+    }
+
+    #[test]
+    fn test_dismiss_button() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let alert = query.single(app.world());
+        let mut buttons = app
+            .world_mut()
+            .query_filtered::<Entity, With<DismissButton>>();
+        let button = buttons.single(app.world());
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(
+            dismissed_events(app.world()),
+            vec![AlertDismissed {
+                entity: alert,
+                message: "disk full".into(),
+                reason: DismissReason::User,
+            }]
+        );
+        // the alert slides out before it is despawned
+        assert!(app.world().get_entity(alert).is_some());
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!(app.world().get_entity(alert).is_none());
+    }
+
+    #[test]
+    fn test_dismissed_reasons() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_secs(1));
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let alert = query.single(app.world());
+        let mut frames = 0;
+        while dismissed_events(app.world()).is_empty() && frames < 10 {
+            app.update();
+            frames += 1;
+        }
+        assert_eq!(
+            dismissed_events(app.world()),
+            vec![AlertDismissed {
+                entity: alert,
+                message: "disk full".into(),
+                reason: DismissReason::Expired,
+            }]
+        );
+        // sent before the alert exits
+        assert!(app.world().get_entity(alert).is_some());
+
+        app.add_systems(Update, corrupt_save.anyhow_alert_sticky());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Sticky>>();
+        let sticky = query.single(app.world());
+        app.world_mut().commands().clear_alerts();
+        app.world_mut().flush();
+        let cleared = dismissed_events(app.world());
+        assert_eq!(
+            cleared.last(),
+            Some(&AlertDismissed {
+                entity: sticky,
+                message: "save file is corrupt".into(),
+                reason: DismissReason::Cleared,
+            })
+        );
+    }
+
+    #[derive(Default, Resource)]
+    struct Triggered(Vec<String>);
+
+    #[test]
+    fn test_alert_raised_triggers() {
+        let mut app = app();
+        app.init_resource::<Triggered>()
+            .observe(
+                |trigger: Trigger<AlertRaised>, mut triggered: ResMut<Triggered>| {
+                    assert_eq!(trigger.entity(), trigger.event().entity);
+                    let message = &trigger.event().message;
+                    triggered.0.push(format!("raised {message}"));
+                },
+            )
+            .observe(
+                |trigger: Trigger<AlertRepeated>, mut triggered: ResMut<Triggered>| {
+                    assert_eq!(trigger.entity(), trigger.event().entity);
+                    let count = trigger.event().count;
+                    triggered.0.push(format!("repeated x{count}"));
+                },
+            );
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Triggered>().0,
+            vec!["raised disk full", "repeated x2"]
+        );
+        let raised = app.world().resource::<Events<AlertRaised>>();
+        assert_eq!(raised.len(), 1);
+    }
+
+    #[test]
+    fn test_no_dismiss_button() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().dismiss_button = false;
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        assert_eq!(shown_count(app.world_mut()), 1);
+        let mut buttons = app.world_mut().query::<&DismissButton>();
+        assert_eq!(buttons.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_click_to_dismiss() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let alert = query.single(app.world());
+        assert!(app.world().get::<Interaction>(alert).is_none());
+        app.world_mut()
+            .resource_mut::<AlertConfig>()
+            .click_to_dismiss = true;
+        app.update();
+        assert_eq!(
+            app.world().get::<FocusPolicy>(alert),
+            Some(&FocusPolicy::Block)
+        );
+        *app.world_mut().get_mut::<Interaction>(alert).unwrap() = Interaction::Pressed;
+        app.update();
+        assert_eq!(
+            dismissed_events(app.world()),
+            vec![AlertDismissed {
+                entity: alert,
+                message: "disk full".into(),
+                reason: DismissReason::User,
+            }]
+        );
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!(app.world().get_entity(alert).is_none());
+    }
+
+    #[test]
+    fn test_click_to_dismiss_disabled() {
+        let mut app = app();
+        app.world_mut()
+            .resource_mut::<AlertConfig>()
+            .click_to_dismiss = true;
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let alert = query.single(app.world());
+        assert!(app.world().get::<Interaction>(alert).is_some());
+        app.world_mut()
+            .resource_mut::<AlertConfig>()
+            .click_to_dismiss = false;
+        app.update();
+        assert!(app.world().get::<Interaction>(alert).is_none());
+        assert_eq!(
+            app.world().get::<FocusPolicy>(alert),
+            Some(&FocusPolicy::Pass)
+        );
+    }
+
+    #[test]
+    fn test_dismiss_hotkeys() {
+        let mut app = app();
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.world_mut().resource_mut::<AlertConfig>().hotkeys = DismissHotkeys {
+            dismiss_latest: Some(Hotkey::new(KeyCode::Escape)),
+            dismiss_all: Some(Hotkey::new(KeyCode::Escape).with_shift()),
+        };
+        app.add_systems(Update, five_errors);
+        app.update();
+        let press = |app: &mut App, keys: &[KeyCode]| {
+            let mut input = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            input.reset_all();
+            for key in keys {
+                input.press(*key);
+            }
+            app.update();
+            let events = app.world().resource::<Events<AlertDismissed>>();
+            let dismissed = events.get_reader().read(events).count();
+            app.world_mut()
+                .resource_mut::<Events<AlertDismissed>>()
+                .clear();
+            dismissed
+        };
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let latest = query.iter(app.world()).max().unwrap();
+        assert_eq!(press(&mut app, &[KeyCode::Escape]), 1);
+        let phase = app.world().get::<AlertPhase>(latest).unwrap();
+        assert_eq!(phase.stage, AlertStage::Exiting);
+        assert_eq!(press(&mut app, &[]), 0);
+        assert_eq!(press(&mut app, &[KeyCode::ShiftLeft, KeyCode::Escape]), 2);
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::Escape));
+    }
+
+    #[derive(Clone, Default)]
+    struct RecordingClipboard(Arc<Mutex<Vec<String>>>);
+
+    impl AlertClipboard for RecordingClipboard {
+        fn set_text(&self, text: String) {
+            self.0.lock().unwrap().push(text);
+        }
+    }
+
+    #[test]
+    fn test_copy_button() {
+        let clipboard = RecordingClipboard::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().with_clipboard(clipboard.clone()));
+        app.world_mut().resource_mut::<AlertConfig>().copy_button = true;
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        app.update();
+        let mut buttons = app.world_mut().query_filtered::<Entity, With<CopyButton>>();
+        let button = buttons.single(app.world());
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(*clipboard.0.lock().unwrap(), vec!["[00:00:00] disk full"]);
+    }
+
+    #[derive(Default, Resource)]
+    struct Retries(usize);
+
+    fn count_retry(mut retries: ResMut<Retries>) {
+        retries.0 += 1;
+    }
+
+    #[test]
+    fn test_retry_button() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.init_resource::<Retries>();
+        let retry = app.world_mut().register_system(count_retry);
+        app.add_systems(Update, disk_full_once.anyhow_alert_with_retry(retry));
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let alert = query.single(app.world());
+        let mut buttons = app
+            .world_mut()
+            .query_filtered::<Entity, With<AlertActionButton>>();
+        let button = buttons.single(app.world());
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(app.world().resource::<Retries>().0, 1);
+        assert_eq!(
+            dismissed_events(app.world()),
+            vec![AlertDismissed {
+                entity: alert,
+                message: "disk full".into(),
+                reason: DismissReason::User,
+            }]
+        );
+        for _ in 0..2 {
+            app.update();
+        }
+        assert!(app.world().get_entity(alert).is_none());
+    }
+
+    #[derive(Default, Resource)]
+    struct Ignored(usize);
+
+    fn count_ignored(mut ignored: ResMut<Ignored>) {
+        ignored.0 += 1;
+    }
+
+    #[test]
+    fn test_alert_actions() {
+        let mut app = app();
+        app.init_resource::<Retries>();
+        app.init_resource::<Ignored>();
+        let retry = app.world_mut().register_system(count_retry);
+        let ignore = app.world_mut().register_system(count_ignored);
+        app.add_systems(
+            Update,
+            disk_full_once.anyhow_alert_actions(vec![
+                AlertAction::new("Retry", retry),
+                AlertAction::new("Ignore", ignore),
+            ]),
+        );
+        app.update();
+        let mut labels = app.world_mut().query::<(&Text, &Parent)>();
+        let ignore_button = labels
+            .iter(app.world())
+            .find(|(text, _)| text.sections[0].value == "Ignore")
+            .map(|(_, parent)| parent.get())
+            .unwrap();
+        let mut buttons = app.world_mut().query::<&AlertActionButton>();
+        assert_eq!(buttons.iter(app.world()).count(), 2);
+        app.world_mut()
+            .entity_mut(ignore_button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(app.world().resource::<Retries>().0, 0);
+        assert_eq!(app.world().resource::<Ignored>().0, 1);
+        assert_eq!(dismissed_events(app.world()).len(), 1);
+    }
+
+    fn fail_with(message: &'static str) -> impl FnMut() -> anyhow::Result<()> {
+        move || Err(anyhow::anyhow!(message))
+    }
+
+    #[test]
+    fn test_alert_reports() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let recorded = reports.clone();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().on_alert(move |report: &AlertReport| {
+            recorded.lock().unwrap().push(report.clone());
+        }));
+        app.add_systems(
+            Update,
+            (
+                failed_loading_settings.anyhow_alert(),
+                fail_with("disk full").warn_alert(),
+            )
+                .chain(),
+        );
+        app.update();
+        app.update();
+        let reports = reports.lock().unwrap();
+        let summary = reports
+            .iter()
+            .map(|report| (report.kind, report.message.as_str(), report.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (AlertReportKind::Raised, "loading settings", 1),
+                (AlertReportKind::Raised, "disk full", 1),
+                (AlertReportKind::Repeated, "loading settings", 2),
+                (AlertReportKind::Repeated, "disk full", 2),
+            ]
+        );
+        assert_eq!(reports[0].chain, vec!["loading settings", "disk full"]);
+        assert_eq!(reports[1].chain, vec!["disk full"]);
+        let mut shown = alert_texts(app.world_mut());
+        shown.sort();
+        assert_eq!(shown, vec!["disk full (x2)", "loading settings (x2)"]);
+    }
+
+    #[derive(Clone, Default)]
+    struct Console(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Console {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Console {
+        fn text(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_console_backend() {
+        let console = Console::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(
+            AlertsPlugin::new().with_backend(ConsoleAlertBackend::to_writer(console.clone())),
+        );
+        app.add_systems(
+            Update,
+            (
+                fail_with("disk full").anyhow_alert(),
+                fail_with("low battery\nplug in the charger").warn_alert(),
+            )
+                .chain(),
+        );
+        app.update();
+        // the repeated messages are folded into the printed blocks until they expire
+        app.update();
+        assert_eq!(
+            console.text(),
+            "+- ERROR ---+\n\
+             | disk full |\n\
+             +-----------+\n\
+             +- WARNING -----------+\n\
+             | low battery         |\n\
+             | plug in the charger |\n\
+             +---------------------+\n"
+        );
+        assert_eq!(raised_count(app.world_mut()), 0);
+    }
+
+    #[test]
+    fn test_console_backend_colors() {
+        let console = Console::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(
+            AlertsPlugin::new()
+                .with_backend(ConsoleAlertBackend::to_writer(console.clone()).with_ansi(true)),
+        );
+        app.add_systems(Update, fail_with("disk full").anyhow_alert());
+        app.update();
+        assert_eq!(
+            console.text(),
+            "\x1b[31m+- ERROR ---+\x1b[0m\n\
+             \x1b[31m|\x1b[0m disk full \x1b[31m|\x1b[0m\n\
+             \x1b[31m+-----------+\x1b[0m\n"
+        );
+    }
+
+    fn shown_messages(world: &mut World) -> Vec<String> {
+        let mut roots = world.query_filtered::<&Children, With<AlertUiRoot>>();
+        let children = roots.single(world).to_vec();
+        children
+            .into_iter()
+            .filter_map(|alert| world.get::<AlertMessage>(alert))
+            .map(|message| message.0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_alert_priority() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().max_visible = Some(2);
+        app.add_systems(
+            Update,
+            (
+                fail_with("autosave skipped").anyhow_alert_priority(1),
+                fail_with("connection lost").anyhow_alert_priority(5),
+                fail_with("texture missing").anyhow_alert_priority(3),
+            )
+                .chain(),
+        );
+        app.update();
+        let queue = app.world().resource::<AlertQueue>();
+        let queued = queue.iter().map(|alert| &*alert.message);
+        assert_eq!(queued.collect::<Vec<_>>(), vec!["autosave skipped"]);
+        assert_eq!(
+            shown_messages(app.world_mut()),
+            vec!["connection lost", "texture missing"]
+        );
+    }
+
+    #[test]
+    fn test_alert_eviction() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().max_visible = Some(2);
+        app.add_systems(
+            Update,
+            (
+                disk_full_once.anyhow_alert_priority(1),
+                corrupt_save.anyhow_alert_priority(2),
+            ),
+        );
+        app.update();
+        let mut query = app.world_mut().query::<(Entity, &AlertMessage)>();
+        let (disk_full, _) = query
+            .iter(app.world())
+            .find(|(_, message)| message.0 == "disk full")
+            .unwrap();
+        // outranks the disk full alert but not the save one
+        app.add_systems(
+            Update,
+            fail_with("connection lost")
+                .anyhow_alert_priority(2)
+                .run_if(run_once()),
+        );
+        app.update();
+        assert!(app.world().get_entity(disk_full).is_none());
+        assert_eq!(
+            dismissed_events(app.world()),
+            vec![AlertDismissed {
+                entity: disk_full,
+                message: "disk full".into(),
+                reason: DismissReason::Evicted,
+            }]
+        );
+        assert_eq!(
+            shown_messages(app.world_mut()),
+            vec!["save file is corrupt", "connection lost"]
+        );
+        // no alert shown has a lower priority to replace
+        app.add_systems(
+            Update,
+            fail_with("autosave skipped")
+                .anyhow_alert_priority(2)
+                .run_if(run_once()),
+        );
+        app.update();
+        assert_eq!(app.world().resource::<AlertQueue>().len(), 1);
+    }
+
+    #[test]
+    fn test_modal_alert() {
+        let mut app = app();
+        app.add_systems(Update, corrupt_save.anyhow_alert_modal());
+        app.update();
+        assert!(app.world().resource::<ModalAlertActive>().0);
+        let mut query = app.world_mut().query_filtered::<Entity, With<ModalAlert>>();
+        let alert = query.single(app.world());
+        let mut scrims = app.world_mut().query_filtered::<Entity, With<ModalScrim>>();
+        let scrim = scrims.single(app.world());
+        assert_eq!(app.world().get::<Parent>(alert).unwrap().get(), scrim);
+        let mut buttons = app
+            .world_mut()
+            .query_filtered::<Entity, With<ModalOkButton>>();
+        let button = buttons.single(app.world());
+        app.world_mut()
+            .entity_mut(button)
+            .insert(Interaction::Pressed);
+        app.update();
+        assert!(!app.world().resource::<ModalAlertActive>().0);
+        assert!(app.world().get_entity(scrim).is_none());
+        assert!(app.world().get_entity(alert).is_none());
+    }
+
+    #[test]
+    fn test_modal_alerts_share_scrim() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                corrupt_save.anyhow_alert_modal(),
+                disk_full_once.anyhow_alert_modal(),
+            ),
+        );
+        app.update();
+        let mut scrims = app
+            .world_mut()
+            .query_filtered::<&Children, With<ModalScrim>>();
+        assert_eq!(scrims.single(app.world()).len(), 2);
+        app.update();
+        let mut scrims = app.world_mut().query_filtered::<(), With<ModalScrim>>();
+        assert_eq!(scrims.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_removed_retry_system() {
+        let mut app = app();
+        app.init_resource::<Retries>();
+        let retry = app.world_mut().register_system(count_retry);
+        app.add_systems(Update, disk_full_once.anyhow_alert_with_retry(retry));
+        app.update();
+        app.world_mut().remove_system(retry).unwrap();
+        app.update();
+        let mut buttons = app
+            .world_mut()
+            .query_filtered::<&Style, With<AlertActionButton>>();
+        assert_eq!(buttons.single(app.world()).display, Display::None);
+    }
+
+    fn long_messages(mut alerts: Alerts) {
+        alerts.extend(["日本語のテキスト", "🦀🦀🦀🦀🦀🦀", "short"].map(String::from));
+    }
+
+    #[test]
+    fn test_max_message_len() {
+        let mut app = app();
+        app.world_mut()
+            .resource_mut::<AlertConfig>()
+            .max_message_len = Some(5);
+        app.add_systems(Update, long_messages);
+        app.update();
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(texts, vec!["short", "日本語の…", "🦀🦀🦀🦀…"]);
+        assert!(texts.iter().all(|text| text.chars().count() <= 5));
+        let mut query = app.world_mut().query::<&AlertMessage>();
+        let mut messages = query
+            .iter(app.world())
+            .map(|message| message.0.as_str())
+            .collect::<Vec<_>>();
+        messages.sort();
+        assert_eq!(messages, vec!["short", "日本語のテキスト", "🦀🦀🦀🦀🦀🦀"]);
+    }
+
+    fn stuck() -> Result<(), String> {
+        Err("stuck".to_string())
+    }
+
+    fn ttl_bar_widths(world: &mut World) -> Vec<Val> {
+        let mut query = world.query_filtered::<&Style, With<TtlBar>>();
+        query.iter(world).map(|style| style.width).collect()
+    }
+
+    #[test]
+    fn test_ttl_bar() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let mut config = app.world_mut().resource_mut::<AlertConfig>();
+        config.show_ttl_bar = true;
+        config.ttl = Some(Duration::from_secs(1));
+        app.add_systems(
+            Update,
+            (disk_full_once.anyhow_alert(), stuck.anyhow_alert_sticky()),
+        );
+        // sticky alerts have no bar, and the bar only shrinks once the alert has slid in
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(ttl_bar_widths(app.world_mut()), vec![Val::Percent(100.)]);
+        for _ in 0..2 {
+            app.update();
+        }
+        assert_eq!(ttl_bar_widths(app.world_mut()), vec![Val::Percent(50.)]);
+    }
+
+    #[test]
+    fn test_severity_icons() {
+        let warning_icon = Handle::<Image>::weak_from_u128(7);
+        let theme = AlertTheme {
+            icons: Some(AlertIcons {
+                warning: AlertIcon::Image(warning_icon.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().with_theme(theme));
+        app.add_systems(Update, (disk_full.anyhow_alert(), disk_full.warn_alert()));
+        app.update();
+        let mut alerts = app
+            .world_mut()
+            .query_filtered::<(&Severity, &Children), With<Alert>>();
+        let bodies = alerts
+            .iter(app.world())
+            .map(|(severity, children)| (*severity, children[1]))
+            .collect::<Vec<_>>();
+        assert_eq!(bodies.len(), 2);
+        for (severity, body) in bodies {
+            let icon = app.world().get::<Children>(body).unwrap()[0];
+            assert!(app.world().get::<SeverityIcon>(icon).is_some());
+            match severity {
+                Severity::Warning => {
+                    let image = app.world().get::<UiImage>(icon).unwrap();
+                    assert_eq!(image.texture, warning_icon);
+                }
+                _ => {
+                    let text = app.world().get::<Text>(icon).unwrap();
+                    assert_eq!(text.sections[0].value, "✖");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_filtered_error_system() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            alternate_error_kinds.anyhow_alert_if(|error| error.is::<DiskFullError>()),
+        );
+        app.update();
+        app.assert_no_alerts();
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["disk full"]);
+        assert_eq!(app.alert_count(), 1);
+    }
+
+    #[test]
+    fn test_filtered_error_collecting_system() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                mixed_error_kinds.anyhow_alerts_if(|error| error.is::<DiskFullError>()),
+                mixed_error_kinds.anyhow_alerts_if(|_| false),
+            ),
+        );
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["disk full"]);
+        assert_eq!(app.alert_count(), 1);
+    }
+
+    #[test]
+    fn test_downcast_routed_error_system() {
+        let mut app = app();
+        app.init_resource::<RecoveredSaves>();
+        app.add_systems(Update, corrupt_save.anyhow_alert_except(recover_save));
+        app.update();
+        assert_eq!(app.world().resource::<RecoveredSaves>().0, 1);
+        app.assert_no_alerts();
+    }
+
+    #[test]
+    fn test_downcast_unrouted_error_system() {
+        let mut app = app();
+        app.init_resource::<RecoveredSaves>();
+        app.add_systems(
+            Update,
+            alternate_error_kinds.anyhow_alert_except::<SaveCorrupt, _>(recover_save),
+        );
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<RecoveredSaves>().0, 0);
+        assert_eq!(app.alert_count(), 2);
+    }
+
+    #[derive(Default, Resource)]
+    struct ReceivedErrors(Vec<String>);
+
+    fn record_errors(
+        mut events: EventReader<ErrorEvent<TestError>>,
+        mut received: ResMut<ReceivedErrors>,
+    ) {
+        received
+            .0
+            .extend(events.read().map(|ErrorEvent(error)| error.to_string()));
+    }
+
+    #[test]
+    fn test_emitting_error_system() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().with_error_events::<TestError>());
+        app.init_resource::<ReceivedErrors>();
+        app.add_systems(
+            Update,
+            (alternate_output.anyhow_alert_and_emit(), record_errors).chain(),
+        );
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<ReceivedErrors>().0, vec!["testing!"]);
+        assert_eq!(app.alert_count(), 1);
+    }
+
+    #[test]
+    fn test_emit_only_error_system() {
+        let mut app = app();
+        app.init_resource::<ReceivedErrors>();
+        app.add_systems(Update, alternate_output.anyhow_emit());
+        app.add_systems(
+            PostUpdate,
+            record_errors.run_if(resource_exists::<Events<ErrorEvent<TestError>>>),
+        );
+        app.update();
+        app.update();
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<ReceivedErrors>().0,
+            vec!["testing!", "testing!"]
+        );
+        app.assert_no_alerts();
+    }
+
+    fn push_disk_full(mut alerts: Alerts) {
+        alerts.push(DiskFullError);
+        alerts.push_with_severity(DiskFullError, Severity::Warning);
+        alerts.extend(["low battery".to_string()]);
+    }
+
+    fn low_battery() -> ResultVec<(), String> {
+        Err(vec!["low battery".to_string()])
+    }
+
+    #[test]
+    fn test_imperative_alerts() {
+        let alerts = |app: &mut App| {
+            app.update();
+            let mut query = app.world_mut().query::<(&Alert, &Severity, &AlertCount)>();
+            let mut alerts = query
+                .iter(app.world())
+                .map(|(_, severity, count)| (*severity, *count))
+                .collect::<Vec<_>>();
+            alerts.sort_by_key(|(severity, _)| *severity);
+            let mut texts = alert_texts(app.world_mut());
+            texts.sort();
+            (alerts, texts)
+        };
+
+        let mut pushed = app();
+        pushed.add_systems(Update, push_disk_full);
+        let mut piped = app();
+        piped.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert(),
+                disk_full.warn_alert(),
+                low_battery.anyhow_alerts(),
+            ),
+        );
+        let pushed = alerts(&mut pushed);
+        assert_eq!(pushed.0.len(), 3);
+        assert_eq!(pushed, alerts(&mut piped));
+    }
+
+    fn missing_spawn_point(mut commands: Commands, mut ran: Local<bool>) {
+        if !*ran {
+            commands.alert("Couldn't find spawn point");
+            commands.alerts(["disk full".to_string(), "low battery".to_string()]);
+            *ran = true;
+        }
+    }
+
+    #[test]
+    fn test_command_alerts() {
+        let mut app = app();
+        app.add_systems(Update, missing_spawn_point);
+        app.update();
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(
+            texts,
+            vec!["Couldn't find spawn point", "disk full", "low battery"]
+        );
+    }
+
+    #[test]
+    fn test_command_queue_alerts() {
+        let mut app = app();
+        let mut queue = CommandQueue::default();
+        queue.push(RaiseAlerts::new("disk full"));
+        queue.push(RaiseAlerts::new("disk full"));
+        queue.apply(app.world_mut());
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["disk full (x2)"]);
+    }
+
+    #[derive(Default, Resource)]
+    struct InlineValues(Vec<Option<u32>>);
+
+    fn report_inline(mut alerts: Alerts, mut values: ResMut<InlineValues>) {
+        values.0.push(Ok::<_, TestError>(1).or_alert(&mut alerts));
+        values.0.push(Err(TestError).or_alert(&mut alerts));
+        values.0.push(
+            Err(DiskFullError).ok_or_alert_with(&mut alerts, |error| format!("saving: {error}")),
+        );
+    }
+
+    #[test]
+    fn test_inline_result_alerts() {
+        let mut app = app();
+        app.init_resource::<InlineValues>();
+        app.add_systems(Update, report_inline);
+        app.update();
+        assert_eq!(
+            app.world().resource::<InlineValues>().0,
+            vec![Some(1), None, None]
+        );
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(texts, vec!["saving: disk full", "testing!"]);
+    }
+
+    fn numbered_errors(mut counter: Local<usize>) -> Result<(), String> {
+        *counter += 1;
+        Err(format!("error {}", *counter))
+    }
+
+    #[test]
+    fn test_alert_history() {
+        let mut app = app();
+        app.insert_resource(AlertHistory::with_capacity(3));
+        app.add_systems(Update, numbered_errors.anyhow_alert());
+        for _ in 0..5 {
+            app.update();
+        }
+        let history = app.world().resource::<AlertHistory>();
+        let messages = history
+            .iter()
+            .map(|entry| entry.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec!["error 3", "error 4", "error 5"]);
+        let latest = history
+            .latest(2)
+            .map(|entry| entry.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(latest, vec!["error 4", "error 5"]);
+        app.world_mut().resource_mut::<AlertHistory>().clear();
+        assert!(app.world().resource::<AlertHistory>().is_empty());
+    }
+
+    #[test]
+    fn test_alert_history_repeats() {
+        let mut app = app();
+        app.add_systems(Update, (disk_full.anyhow_alert(), push_disk_full).chain());
+        app.update();
+        app.update();
+        let history = app.world().resource::<AlertHistory>();
+        let entries = history
+            .iter()
+            .map(|entry| (entry.message.as_str(), entry.severity, entry.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![
+                ("disk full", Severity::Error, 2),
+                ("disk full", Severity::Warning, 1),
+                ("low battery", Severity::Error, 1),
+                ("disk full", Severity::Error, 2),
+                ("disk full", Severity::Warning, 1),
+                ("low battery", Severity::Error, 1),
+            ]
+        );
+    }
+
+    fn frequent_errors(mut alerts: Alerts, mut counter: Local<usize>) {
+        *counter += 1;
+        alerts.push("every frame");
+        if *counter % 2 == 1 {
+            alerts.push("every other frame");
+        }
+        if *counter == 3 {
+            alerts.push("once");
+        }
+    }
+
+    #[test]
+    fn test_error_stats() {
+        let mut app = app();
+        app.add_systems(Update, frequent_errors);
+        for _ in 0..4 {
+            app.update();
+        }
+        let stats = app.world().resource::<ErrorStats>();
+        let top = stats
+            .top(2)
+            .into_iter()
+            .map(|(message, stat)| (message, stat.count))
+            .collect::<Vec<_>>();
+        assert_eq!(top, vec![("every frame", 4), ("every other frame", 2)]);
+        assert_eq!(stats.get("once").map(|stat| stat.count), Some(1));
+        app.world_mut().resource_mut::<ErrorStats>().reset();
+        assert!(app.world().resource::<ErrorStats>().top(2).is_empty());
+    }
+
+    #[test]
+    fn test_clear_alerts_event() {
+        let mut app = app();
+        app.add_systems(Update, push_disk_full);
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 3);
+        app.world_mut().send_event(ClearAlerts);
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 0);
+        assert!(alert_texts(app.world_mut()).is_empty());
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 3);
+        let mut query = app.world_mut().query::<&AlertCount>();
+        assert!(query.iter(app.world()).all(|count| count.0 == 1));
+    }
+
+    fn clear_alerts(mut commands: Commands) {
+        commands.clear_alerts();
+    }
+
+    #[test]
+    fn test_clear_alerts_command() {
+        let mut app = app();
+        app.add_systems(Update, (disk_full.anyhow_alert(), clear_alerts).chain());
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 0);
+        app.add_systems(Update, push_disk_full.after(clear_alerts));
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 3);
+    }
+
+    /// Stands in for `OnExit(GameState::Menu)`, as `bevy_state` isn't a dependency.
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct ExitMenu;
+
+    #[test]
+    fn test_clear_on_state_change() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().clear_in(ExitMenu));
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        let mut query = app.world_mut().query_filtered::<Entity, With<Alert>>();
+        let menu_alert = query.single(app.world());
+        app.world_mut().run_schedule(ExitMenu);
+        assert!(app.world().get_entity(menu_alert).is_none());
+        let reasons = dismissed_events(app.world())
+            .into_iter()
+            .map(|event| event.reason)
+            .collect::<Vec<_>>();
+        assert_eq!(reasons, vec![DismissReason::StateChanged]);
+        app.update();
+        let mut query = app.world_mut().query::<(Entity, &AlertCount)>();
+        let (alert, count) = query.single(app.world());
+        assert_ne!(alert, menu_alert);
+        assert_eq!(*count, AlertCount(1));
+    }
+
+    fn five_errors(mut alerts: Alerts, mut ran: Local<bool>) {
+        if !*ran {
+            alerts.extend((1..=5).map(|n| format!("error {n}")));
+            *ran = true;
+        }
+    }
+
+    fn shown_count(world: &mut World) -> usize {
+        let mut query = world.query_filtered::<(), (With<Alert>, With<AlertUi>)>();
+        query.iter(world).count()
+    }
+
+    #[test]
+    fn test_max_visible_config() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().max_visible = Some(2);
+        app.add_systems(Update, five_errors);
+        app.update();
+        assert_eq!(shown_count(app.world_mut()), 2);
+    }
+
+    #[test]
+    fn test_ttl_in_real_time() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_secs(1));
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        // alerts slide in and expire while the game is paused
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(shown_count(app.world_mut()), 0);
+    }
+
+    #[test]
+    fn test_ttl_config() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_secs(1));
+        app.add_systems(Update, five_errors);
+        app.update();
+        assert_eq!(shown_count(app.world_mut()), 3);
+        // 0.5s to slide in, 1s to expire and 0.5s to slide out
+        for _ in 0..9 {
+            app.update();
+        }
+        assert_eq!(shown_count(app.world_mut()), 2);
+    }
+
+    #[test]
+    fn test_no_ttl_config() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().ttl = None;
+        app.add_systems(Update, five_errors);
+        for _ in 0..60 {
+            app.update();
+        }
+        assert_eq!(shown_count(app.world_mut()), 3);
+    }
+
+    #[test]
+    fn test_min_severity_config() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().min_severity = Severity::Warning;
+        app.add_systems(
+            Update,
+            (
+                disk_full.info_alert(),
+                disk_full.warn_alert(),
+                disk_full.anyhow_alert(),
+            )
+                .chain(),
+        );
+        app.update();
+        let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+        let mut severities = query.iter(app.world()).copied().collect::<Vec<_>>();
+        severities.sort();
+        assert_eq!(severities, vec![Severity::Warning, Severity::Error]);
+        let history = app.world().resource::<AlertHistory>();
+        let recorded = history
+            .iter()
+            .map(|entry| entry.severity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recorded,
+            vec![Severity::Info, Severity::Warning, Severity::Error]
+        );
+        // lowering the threshold only shows the alerts raised afterwards
+        app.world_mut().resource_mut::<AlertConfig>().min_severity = Severity::Info;
+        app.update();
+        let mut query = app.world_mut().query::<(&Severity, &AlertCount)>();
+        let info = query
+            .iter(app.world())
+            .find(|(severity, _)| **severity == Severity::Info);
+        assert_eq!(info.map(|(_, count)| *count), Some(AlertCount(1)));
+    }
+
+    fn disk_full_once(mut ran: Local<bool>) -> Result<(), DiskFullError> {
+        if *ran {
+            Ok(())
+        } else {
+            *ran = true;
+            Err(DiskFullError)
+        }
+    }
+
+    fn disk_full_many_once(mut ran: Local<bool>) -> ResultVec<(), DiskFullError> {
+        if *ran {
+            Ok(())
+        } else {
+            *ran = true;
+            Err(vec![DiskFullError])
+        }
+    }
+
+    #[test]
+    fn test_ttl_override() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(
+            Update,
+            (
+                disk_full_once.anyhow_alert_ttl(Duration::from_secs(1)),
+                disk_full_many_once.warn_alerts(),
+            ),
+        );
+        app.update();
+        assert_eq!(shown_count(app.world_mut()), 2);
+        let mut query = app.world_mut().query::<(&Severity, &Ttl)>();
+        let mut ttls = query
+            .iter(app.world())
+            .map(|(severity, ttl)| (*severity, ttl.0))
+            .collect::<Vec<_>>();
+        ttls.sort();
+        assert_eq!(
+            ttls,
+            vec![
+                (Severity::Warning, Duration::from_secs(10)),
+                (Severity::Error, Duration::from_secs(1)),
+            ]
+        );
+        // past the custom ttl and the fade out, but well within the default ttl
+        for _ in 0..8 {
+            app.update();
+        }
+        let mut query = app.world_mut().query_filtered::<&Severity, With<Alert>>();
+        let severities = query.iter(app.world()).copied().collect::<Vec<_>>();
+        assert_eq!(severities, vec![Severity::Warning]);
+    }
+
+    #[test]
+    fn test_collecting_ttl_override() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            disk_full_many_once.anyhow_alerts_ttl(Duration::from_secs(3)),
+        );
+        app.update();
+        let mut query = app.world_mut().query::<&Ttl>();
+        let ttls = query.iter(app.world()).copied().collect::<Vec<_>>();
+        assert_eq!(ttls, vec![Ttl(Duration::from_secs(3))]);
+    }
+
+    #[test]
+    fn test_zero_and_max_ttl() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(
+            Update,
+            (
+                disk_full_once.anyhow_alert_ttl(Duration::ZERO),
+                low_battery.anyhow_alerts_ttl(Duration::MAX),
+            ),
+        );
         app.update();
+        assert_eq!(shown_count(app.world_mut()), 2);
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["low battery (x2)"]);
+        for _ in 0..60 {
+            app.update();
+        }
+        assert_eq!(shown_count(app.world_mut()), 1);
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(), (With<Alert>, With<Ttl>)>();
         assert_eq!(query.iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_sticky_alerts() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(
+            Update,
+            (
+                disk_full_once.anyhow_alert(),
+                disk_full_many_once.warn_alerts(),
+                corrupt_save.anyhow_alert_sticky(),
+            )
+                .chain(),
+        );
+        app.update();
+        // pinned above the alerts raised before it
+        let mut roots = app
+            .world_mut()
+            .query_filtered::<&Children, With<AlertUiRoot>>();
+        let first = roots.single(app.world())[0];
+        assert!(app.world().entity(first).contains::<Sticky>());
+        // well past the default ttl
+        for _ in 0..60 {
+            app.update();
+        }
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(), (With<Alert>, With<Sticky>)>();
+        assert_eq!(query.iter(app.world()).count(), 1);
+        assert_eq!(
+            alert_texts(app.world_mut()),
+            vec!["save file is corrupt (x61)"]
+        );
+        app.world_mut().send_event(ClearAlerts);
+        app.update();
+        assert_eq!(raised_count(app.world_mut()), 0);
+    }
+
+    #[test]
+    fn test_sticky_collecting_alerts() {
+        let mut app = app();
+        app.add_systems(Update, disk_full_many_once.anyhow_alerts_sticky());
         app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(), (With<Alert>, With<Sticky>, Without<Ttl>)>();
         assert_eq!(query.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_persistent_error_stays_bounded() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        // short enough that the alert is respawned over and over
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_secs(1));
+        app.add_systems(Update, disk_full.anyhow_alert());
+        for _ in 0..100 {
+            app.update();
+        }
+        let warmed_up = app.world().entities().total_count();
+        let mut alerts = app.world_mut().query_filtered::<(), With<Alert>>();
+        for _ in 0..1000 {
+            app.update();
+            assert!(alerts.iter(app.world()).count() <= 1);
+        }
+        // despawned alerts free their entities for the next ones
+        assert!(app.world().entities().total_count() <= warmed_up);
+        assert!(app.world().resource::<AlertQueue>().is_empty());
+        assert!(app.world().resource::<AlertHistory>().len() <= AlertHistory::DEFAULT_CAPACITY);
+    }
+
+    fn three_in_sequence(mut alerts: Alerts, mut raised: Local<usize>) {
+        if *raised < 3 {
+            alerts.push(format!("alert {}", *raised));
+            *raised += 1;
+        }
+    }
+
+    fn stacked_messages(world: &mut World) -> Vec<String> {
+        let mut roots = world.query_filtered::<&Children, With<AlertUiRoot>>();
+        let children = roots.single(world).to_vec();
+        children
+            .into_iter()
+            .map(|alert| world.get::<AlertMessage>(alert).unwrap().0.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_stack_direction() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().stack = StackDirection::NewestOnTop;
+        app.add_systems(Update, three_in_sequence);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(
+            stacked_messages(app.world_mut()),
+            vec!["alert 2", "alert 1", "alert 0"]
+        );
+        // existing alerts are re-sorted when the setting changes
+        app.world_mut().resource_mut::<AlertConfig>().stack = StackDirection::NewestOnBottom;
+        app.update();
+        assert_eq!(
+            stacked_messages(app.world_mut()),
+            vec!["alert 0", "alert 1", "alert 2"]
+        );
+    }
+
+    fn battery_saver() -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("battery saver enabled"))
+    }
+
+    #[test]
+    fn test_same_frame_order() {
+        for _ in 0..5 {
+            let mut app = app();
+            app.add_systems(
+                Update,
+                (
+                    battery_saver.info_alert(),
+                    disk_full.anyhow_alert(),
+                    low_battery.warn_alerts(),
+                ),
+            );
+            app.update();
+            // the most severe first, whichever system ran first
+            assert_eq!(
+                stacked_messages(app.world_mut()),
+                vec!["disk full", "low battery", "battery saver enabled"]
+            );
+        }
+    }
+
+    fn channel_of(world: &mut World, message: &str) -> Option<String> {
+        let mut alerts = world.query::<(&AlertMessage, &Parent)>();
+        let (_, parent) = alerts
+            .iter(world)
+            .find(|(alert, _)| alert.0 == message)
+            .unwrap();
+        let root = world.entity(parent.get());
+        assert!(root.contains::<AlertUiRoot>() || root.contains::<AlertChannelRoot>());
+        root.get::<AlertChannelRoot>().map(|root| root.0.clone())
+    }
+
+    #[test]
+    fn test_alert_channels() {
+        let mut app = App::new();
+        let network = ChannelConfig {
+            theme: AlertTheme {
+                anchor: AlertAnchor::BottomLeft,
+                ..Default::default()
+            },
+            max_visible: Some(1),
+        };
+        app.add_plugins(MinimalPlugins).add_plugins(
+            AlertsPlugin::new()
+                .with_channel("system", ChannelConfig::default())
+                .with_channel("network", network),
+        );
+        app.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert_channel("system"),
+                corrupt_save.anyhow_alert_channel("network"),
+                battery_saver.anyhow_alert_channel("chat"),
+                disk_full.anyhow_alert_channel("network"),
+            )
+                .chain(),
+        );
+        app.update();
+        assert_eq!(
+            channel_of(app.world_mut(), "disk full"),
+            Some("system".into())
+        );
+        assert_eq!(
+            channel_of(app.world_mut(), "save file is corrupt"),
+            Some("network".into())
+        );
+        // unregistered channels fall back to the default alerts
+        assert_eq!(channel_of(app.world_mut(), "battery saver enabled"), None);
+        // not folded into the alert of the other channel, but over the channel's limit
+        let queue = app.world().resource::<AlertQueue>();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(
+            queue.iter().next().unwrap().channel.as_deref(),
+            Some("network")
+        );
+        let mut roots = app.world_mut().query::<(&AlertChannelRoot, &Style)>();
+        let (_, style) = roots
+            .iter(app.world())
+            .find(|(root, _)| root.0 == "network")
+            .unwrap();
+        assert_eq!(style.left, Val::Px(24.));
+        assert_eq!(style.bottom, Val::Px(24.));
+    }
+
+    #[derive(Component, Default)]
+    struct AudioAlert;
+
+    #[test]
+    fn test_tagged_alerts() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert_tagged::<AudioAlert, _>(),
+                corrupt_save.anyhow_alert(),
+            ),
+        );
         app.update();
+        let mut query = app.world_mut().query_filtered::<&Alert, With<AudioAlert>>();
         assert_eq!(query.iter(app.world()).count(), 1);
+    }
+
+    #[test]
+    fn test_alert_options() {
+        let mut app = app();
+        let options = AlertOptions::new()
+            .with_severity(Severity::Warning)
+            .with_ttl(Duration::from_secs(60))
+            .with_tag::<AudioAlert>();
+        app.add_systems(Update, disk_full.anyhow_alert_with(options));
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<(&Severity, &Ttl), (With<Alert>, With<AudioAlert>)>();
+        let (severity, ttl) = query.single(app.world());
+        assert_eq!(*severity, Severity::Warning);
+        assert_eq!(ttl.0, Duration::from_secs(60));
+    }
+
+    #[derive(Debug, Error)]
+    enum LoadError {
+        #[error("level {0} is missing")]
+        Missing(&'static str),
+        #[error("level is corrupt")]
+        #[allow(dead_code)]
+        Corrupt,
+    }
+
+    fn load_forest() -> Result<(), LoadError> {
+        Err(LoadError::Missing("forest"))
+    }
+
+    #[test]
+    fn test_error_payload() {
+        let mut app = app();
+        app.add_systems(Update, load_forest.anyhow_alert());
+        app.update();
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&ErrorPayload<LoadError>, With<Alert>>();
+        let ErrorPayload(error) = query.single(app.world());
+        assert!(matches!(error, LoadError::Missing("forest")));
+    }
+
+    #[test]
+    fn test_alert_root_override() {
+        let mut app = app();
+        let panel = app.world_mut().spawn(NodeBundle::default()).id();
+        app.insert_resource(AlertRootOverride(panel));
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let mut alerts = app.world_mut().query_filtered::<&Parent, With<Alert>>();
+        assert_eq!(alerts.single(app.world()).get(), panel);
+        let mut roots = app
+            .world_mut()
+            .query_filtered::<&Style, With<AlertUiRoot>>();
+        assert_eq!(roots.single(app.world()).display, Display::None);
+        // falls back to the default root, warning once
+        app.world_mut().entity_mut(panel).despawn_recursive();
+        app.edit_schedule(PostUpdate, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        });
+        let logs = capture_logs(&mut app);
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].0, Level::WARN);
+        assert!(capture_logs(&mut app).is_empty());
+        let root = app
+            .world_mut()
+            .query_filtered::<Entity, With<AlertUiRoot>>()
+            .single(app.world());
+        assert_eq!(alerts.single(app.world()).get(), root);
+        assert_eq!(roots.single(app.world()).display, Display::Flex);
+    }
+
+    fn ten_distinct_errors(mut ran: Local<bool>) -> ResultVec<(), String> {
+        if *ran {
+            return Ok(());
+        }
+        *ran = true;
+        Err((0..10).map(|n| format!("error {n}")).collect())
+    }
+
+    #[test]
+    fn test_queued_alerts() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().ttl = Some(Duration::from_secs(1));
+        app.add_systems(Update, ten_distinct_errors.anyhow_alerts());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<(), With<Alert>>();
+        assert_eq!(query.iter(app.world()).count(), 3);
+        let queue = app.world().resource::<AlertQueue>();
+        assert_eq!(queue.len(), 7);
+        assert_eq!(queue.iter().next().unwrap().message.as_ref(), "error 3");
+        // each batch of 3 takes 0.5s to slide in, 1s to expire and 0.5s to slide out
+        for _ in 0..9 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<AlertQueue>().len(), 4);
+        for _ in 0..27 {
+            app.update();
+        }
+        assert!(app.world().resource::<AlertQueue>().is_empty());
+    }
+
+    #[test]
+    fn test_queued_alert_dedup() {
+        let mut app = app();
+        app.world_mut().resource_mut::<AlertConfig>().max_visible = Some(0);
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        let queue = app.world().resource::<AlertQueue>();
+        let queued = queue.iter().map(|queued| queued.count).collect::<Vec<_>>();
+        assert_eq!(queued, vec![2]);
+        app.world_mut().resource_mut::<AlertConfig>().max_visible = None;
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["disk full (x3)"]);
+    }
+
+    fn failed_loading_save() -> anyhow::Result<()> {
+        Err(anyhow::Error::new(DiskFullError)
+            .context("reading save file")
+            .context("loading game"))
+    }
+
+    fn failed_loading_saves() -> ResultVec<(), anyhow::Error> {
+        failed_loading_save().map_err(|error| vec![error])
+    }
+
+    #[test]
+    fn test_error_chain_system() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                failed_loading_save.anyhow_alert_chain(),
+                disk_full.anyhow_alert_chain(),
+            ),
+        );
+        app.update();
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(
+            texts,
+            vec!["disk full", "loading game: reading save file: disk full"]
+        );
+    }
+
+    #[test]
+    fn test_error_chain_collecting_system() {
+        let mut app = app();
+        app.add_systems(Update, failed_loading_saves.anyhow_alerts_chain());
+        app.update();
+        assert_eq!(
+            alert_texts(app.world_mut()),
+            vec!["loading game: reading save file: disk full"]
+        );
+    }
+
+    /// An error type that implements `Debug` but not `Display`.
+    #[derive(Debug)]
+    struct DebugOnlyError {
+        #[allow(dead_code)]
+        code: u32,
+    }
+
+    fn debug_only_error() -> Result<(), DebugOnlyError> {
+        Err(DebugOnlyError { code: 7 })
+    }
+
+    fn debug_only_errors() -> ResultVec<(), DebugOnlyError> {
+        Err(vec![DebugOnlyError { code: 8 }])
+    }
+
+    #[test]
+    fn test_debug_error_system() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                debug_only_error.anyhow_alert_debug(),
+                debug_only_errors.anyhow_alerts_debug(),
+            ),
+        );
+        app.update();
+        let mut texts = alert_texts(app.world_mut());
+        texts.sort();
+        assert_eq!(
+            texts,
+            vec!["DebugOnlyError { code: 7 }", "DebugOnlyError { code: 8 }"]
+        );
+    }
+
+    #[cfg(feature = "diagnostics")]
+    #[test]
+    fn test_alert_diagnostics() {
+        use bevy::diagnostic::{DiagnosticPath, DiagnosticsPlugin, DiagnosticsStore};
+
+        let mut app = app();
+        app.add_plugins((DiagnosticsPlugin, AlertDiagnosticsPlugin));
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert(),
+                ten_distinct_errors.anyhow_alerts(),
+            ),
+        );
+        for _ in 0..2 {
+            app.update();
+        }
+        let store = app.world().resource::<DiagnosticsStore>();
+        let value =
+            |path: &DiagnosticPath| store.get(path).and_then(|diagnostic| diagnostic.value());
+        // ten distinct errors once, and the same error twice
+        assert_eq!(value(&AlertDiagnosticsPlugin::RAISED_TOTAL), Some(12.));
+        // `max_visible` alerts are spawned, the rest are queued
+        assert_eq!(value(&AlertDiagnosticsPlugin::ACTIVE), Some(3.));
+        // one error over the 250ms frame
+        assert_eq!(value(&AlertDiagnosticsPlugin::RAISED_PER_SECOND), Some(4.));
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn test_alert_backtraces() {
+        let mut app = app();
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let mut query = app.world_mut().query_filtered::<&Backtrace, With<Alert>>();
+        let backtrace = query.single(app.world()).clone();
+        let history = app.world().resource::<AlertHistory>();
+        assert_eq!(history.iter().next().unwrap().backtrace, backtrace);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_history_export() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert(),
+                coded_save.anyhow_alert_coded(),
+                fail_with("connection lost").anyhow_alert_with_severity(Severity::Warning),
+            ),
+        );
+        app.update();
+        // the backtraces aren't serialized
+        let fields = |entry: &AlertHistoryEntry| {
+            let AlertHistoryEntry {
+                message,
+                severity,
+                raised_at,
+                frame,
+                last_seen,
+                count,
+                meta,
+                ..
+            } = entry.clone();
+            (message, severity, raised_at, frame, last_seen, count, meta)
+        };
+        let history = app.world().resource::<AlertHistory>();
+        let entries = history.iter().map(fields).collect::<Vec<_>>();
+        assert_eq!(entries.len(), 3);
+        let parsed = AlertHistory::entries_from_ron(&history.to_ron()).unwrap();
+        assert_eq!(parsed.iter().map(fields).collect::<Vec<_>>(), entries);
+
+        let path = std::env::temp_dir().join(format!("alerts-{}.ron", std::process::id()));
+        app.world_mut()
+            .commands()
+            .add(DumpAlertHistory { path: path.clone() });
+        app.world_mut().flush();
+        let dumped = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let parsed = AlertHistory::entries_from_ron(&dumped).unwrap();
+        assert_eq!(parsed.iter().map(fields).collect::<Vec<_>>(), entries);
+    }
+
+    #[cfg(feature = "serde")]
+    fn persisted_app(path: &std::path::Path) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().persist_history(path));
+        app
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_persisted_history() {
+        fn flush(app: &mut App) {
+            app.world_mut().commands().add(FlushAlertHistory);
+            app.world_mut().flush();
+        }
+        fn entries(app: &App) -> Vec<(String, usize, bool)> {
+            let history = app.world().resource::<AlertHistory>();
+            history
+                .iter()
+                .map(|entry| (entry.message.clone(), entry.count, entry.previous_session))
+                .collect()
+        }
+        let path = std::env::temp_dir().join(format!("persisted-{}.ron", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut app = persisted_app(&path);
+        app.add_systems(Update, disk_full_once.anyhow_alert());
+        app.update();
+        flush(&mut app);
+
+        let mut app = persisted_app(&path);
+        app.add_systems(
+            Update,
+            (disk_full_once.anyhow_alert(), corrupt_save.anyhow_alert()).chain(),
+        );
+        app.update();
+        assert_eq!(
+            entries(&app),
+            vec![
+                ("disk full".to_string(), 1, true),
+                ("disk full".to_string(), 1, false),
+                ("save file is corrupt".to_string(), 1, false)
+            ]
+        );
+        assert_eq!(app.alert_count(), 2);
+        flush(&mut app);
+        // the second error is folded into the entry written already, which is written again
+        app.update();
+        flush(&mut app);
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines, 4);
+
+        // the entries of both runs are restored, and the file is compacted to them
+        let mut app = persisted_app(&path);
+        app.update();
+        assert_eq!(
+            entries(&app),
+            vec![
+                ("disk full".to_string(), 1, true),
+                ("disk full".to_string(), 1, true),
+                ("save file is corrupt".to_string(), 2, true)
+            ]
+        );
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(lines, 3);
+
+        // a line cut short by a crash is skipped
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        std::io::Write::write_all(&mut file, b"(message:\"disk").unwrap();
+        let mut app = persisted_app(&path);
+        app.update();
+        assert_eq!(app.world().resource::<AlertHistory>().len(), 3);
+
+        std::fs::write(&path, "not an alert history").unwrap();
+        let mut app = persisted_app(&path);
+        app.update();
+        assert!(app.world().resource::<AlertHistory>().is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "file_log")]
+    #[test]
+    fn test_file_log() {
+        let path = std::env::temp_dir().join(format!("errors-{}.log", std::process::id()));
+        let rotated = path.with_extension("log.1");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&rotated);
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new().log_to_file(&path, 64));
+        app.add_systems(Update, corrupt_save.anyhow_alert());
+        app.update();
+        app.update();
+        app.world().resource::<FileAlertSink>().flush();
+        assert_eq!(
+            std::fs::read_to_string(&rotated).unwrap(),
+            "[00:00:00] Error x1 save file is corrupt\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "[00:00:00] Error x2 save file is corrupt\n"
+        );
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&rotated).unwrap();
+    }
+
+    #[test]
+    fn test_named_error_system() {
+        let mut app = app();
+        app.add_systems(Update, disk_full.anyhow_alert_named());
+        app.update();
+        let texts = alert_texts(app.world_mut());
+        assert!(texts[0].starts_with("bevy_anyhow_alert::tests::disk_full: "));
+    }
+
+    #[test]
+    fn test_named_error_collecting_system() {
+        let mut app = app();
+        app.add_systems(Update, disk_full_many.anyhow_alerts_named());
+        app.update();
+        assert_eq!(
+            alert_texts(app.world_mut()),
+            vec!["bevy_anyhow_alert::tests::disk_full_many: disk full"]
+        );
+    }
+
+    #[test]
+    fn test_alert_timestamps() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.world_mut().resource_mut::<AlertConfig>().max_visible = None;
+        app.add_systems(
+            Update,
+            (numbered_errors.anyhow_alert(), disk_full.anyhow_alert()),
+        );
+        for _ in 0..3 {
+            app.update();
+        }
+        let mut query = app.world_mut().query::<(&AlertMessage, &RaisedAt)>();
+        let mut stamps = query
+            .iter(app.world())
+            .map(|(message, raised_at)| (message.0.clone(), *raised_at))
+            .collect::<Vec<_>>();
+        stamps.sort_by_key(|(message, _)| message.clone());
+        let stamps = stamps
+            .into_iter()
+            .map(|(_, stamp)| stamp)
+            .collect::<Vec<_>>();
+        // "disk full" was raised on every frame, then "error 1" to "error 3"
+        let [disk_full, first, second, third] = stamps[..] else {
+            panic!("expected 4 alerts, found {}", stamps.len());
+        };
+        assert!(first.real_time < second.real_time && second.real_time < third.real_time);
+        assert!(first.frame < second.frame && second.frame < third.frame);
+        assert_eq!(disk_full.real_time, first.real_time);
+        assert_eq!(disk_full.last_seen, third.real_time);
+        assert_eq!(third.frame, app.world().resource::<FrameCount>().0 - 1);
+        let history = app.world().resource::<AlertHistory>();
+        let frames = history.iter().map(|entry| entry.frame).collect::<Vec<_>>();
+        assert!(frames.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn test_show_timestamps() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        for _ in 0..5 {
+            app.update();
+        }
+        app.world_mut()
+            .resource_mut::<AlertConfig>()
+            .show_timestamps = true;
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        assert_eq!(
+            alert_texts(app.world_mut()),
+            vec!["[00:00:01] disk full (x2)"]
+        );
+        app.world_mut()
+            .resource_mut::<AlertConfig>()
+            .show_timestamps = false;
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["disk full (x3)"]);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(
+        expected = "bevy_anyhow_alert::tests::failed_loading_settings returned an error: \
+        loading settings: disk full"
+    )]
+    fn test_panicking_error_system() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            failed_loading_settings.anyhow_alert_or_panic_debug(),
+        );
+        app.update();
+    }
+
+    #[test]
+    fn test_panic_or_alert_release_path() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            failed_loading_settings.pipe(panic_or_alert_pipe::<(), (), _>("unused".into(), false)),
+        );
+        app.update();
+        assert_eq!(alert_texts(app.world_mut()), vec!["loading settings"]);
+    }
+}
+
+#[cfg(all(test, not(feature = "ui")))]
+mod core_tests {
+    use super::*;
+    use bevy::prelude::*;
+
+    fn disk_full() -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("disk full"))
+    }
+
+    #[test]
+    fn test_alerts_without_ui() {
+        let mut app = app();
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        let events = app.world().resource::<Events<RaisedAlert>>();
+        let messages: Vec<_> = events
+            .iter_current_update_events()
+            .map(|alert| (&*alert.message, alert.severity))
+            .collect();
+        assert_eq!(messages, vec![("disk full", Severity::Error)]);
+        assert_eq!(app.world().resource::<AlertHistory>().len(), 1);
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(AlertsPlugin::new());
+        app
+    }
+
+    fn raised(app: &App) -> Vec<(String, Severity)> {
+        let events = app.world().resource::<Events<RaisedAlert>>();
+        let mut raised: Vec<_> = events
+            .get_reader()
+            .read(events)
+            .map(|alert| (alert.message.to_string(), alert.severity))
+            .collect();
+        raised.sort();
+        raised
+    }
+
+    fn history(app: &App) -> Vec<(String, Severity, usize)> {
+        let history = app.world().resource::<AlertHistory>();
+        let mut entries: Vec<_> = history
+            .iter()
+            .map(|entry| (entry.message.clone(), entry.severity, entry.count))
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    fn low_battery() -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("low battery"))
+    }
+
+    fn update_available() -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("update available"))
+    }
+
+    #[test]
+    fn test_log_backend_severities() {
+        let mut app = app();
+        app.add_systems(
+            Update,
+            (
+                disk_full.anyhow_alert(),
+                low_battery.warn_alert(),
+                update_available.info_alert(),
+            ),
+        );
+        app.update();
+        let expected = vec![
+            ("disk full".to_string(), Severity::Error),
+            ("low battery".to_string(), Severity::Warning),
+            ("update available".to_string(), Severity::Info),
+        ];
+        assert_eq!(raised(&app), expected);
+        let entries: Vec<_> = expected
+            .into_iter()
+            .map(|(message, severity)| (message, severity, 1))
+            .collect();
+        assert_eq!(history(&app), entries);
+    }
+
+    #[test]
+    fn test_log_backend_repeated_alerts() {
+        let mut app = app();
+        app.add_systems(Update, disk_full.anyhow_alert());
+        app.update();
+        app.update();
+        // every repeat is sent as an event, but folded into a single history entry
+        assert_eq!(
+            raised(&app),
+            vec![
+                ("disk full".to_string(), Severity::Error),
+                ("disk full".to_string(), Severity::Error),
+            ]
+        );
+        assert_eq!(
+            history(&app),
+            vec![("disk full".to_string(), Severity::Error, 2)]
+        );
+    }
+
+    #[test]
+    fn test_log_backend_sender() {
+        let mut app = app();
+        let sender = app.world().resource::<AlertSender>().clone();
+        std::thread::spawn(move || {
+            sender.send("download failed");
+            sender.send_with_severity("download slow", Severity::Warning);
+        })
+        .join()
+        .unwrap();
         app.update();
-        assert_eq!(query.iter(app.world()).count(), 2);
+        assert_eq!(
+            raised(&app),
+            vec![
+                ("download failed".to_string(), Severity::Error),
+                ("download slow".to_string(), Severity::Warning),
+            ]
+        );
+        assert_eq!(
+            history(&app),
+            vec![
+                ("download failed".to_string(), Severity::Error, 1),
+                ("download slow".to_string(), Severity::Warning, 1),
+            ]
+        );
     }
 }
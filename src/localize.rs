@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+
+/// Translates the messages of alerts before they are shown.
+///
+/// When this resource is inserted, the text shown by each alert is looked up with the alert
+/// message, or with the [`LocalizableError::l10n_key`] of the error it was raised for. The
+/// original message is still the [`AlertMessage`](crate::AlertMessage) of the alert, used for
+/// the logs, the [`AlertHistory`](crate::AlertHistory), and the clipboard. Messages the
+/// localizer returns `None` for are shown untranslated.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// let mut app = App::new();
+/// app.insert_resource(AlertLocalizer::new(|key| match key {
+///     "disk full" => Some("Le disque est plein".into()),
+///     _ => None,
+/// }));
+/// ```
+#[derive(Clone, Resource)]
+pub struct AlertLocalizer(Arc<Translate>);
+
+type Translate = dyn Fn(&str) -> Option<String> + Send + Sync;
+
+impl AlertLocalizer {
+    /// Builds a localizer looking up each message or key with `translate`.
+    pub fn new(translate: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        Self(Arc::new(translate))
+    }
+
+    /// The text shown for `message`, or `None` if it isn't translated.
+    ///
+    /// With `l10n`, its key is looked up instead of the message, and each `{name}` in the
+    /// translation is replaced with the argument `name`.
+    pub fn localize(&self, message: &str, l10n: Option<&AlertL10n>) -> Option<String> {
+        let Some(l10n) = l10n else {
+            return (self.0)(message);
+        };
+        let mut text = (self.0)(&l10n.key)?;
+        for (name, value) in &l10n.args {
+            text = text.replace(&format!("{{{name}}}"), value);
+        }
+        Some(text)
+    }
+}
+
+impl std::fmt::Debug for AlertLocalizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlertLocalizer").finish_non_exhaustive()
+    }
+}
+
+/// Implemented by error types looked up by key in the [`AlertLocalizer`], see
+/// [`AnyhowAlertExt::anyhow_alert_localized`](crate::AnyhowAlertExt::anyhow_alert_localized).
+///
+/// ```
+/// use bevy_anyhow_alert::*;
+///
+/// #[derive(Debug, thiserror::Error)]
+/// #[error("level {0} failed to load")]
+/// struct LevelError(u32);
+///
+/// impl LocalizableError for LevelError {
+///     fn l10n_key(&self) -> &str {
+///         "level-load-failed"
+///     }
+///
+///     fn args(&self) -> Vec<(&'static str, String)> {
+///         vec![("level", self.0.to_string())]
+///     }
+/// }
+/// ```
+pub trait LocalizableError {
+    /// The key of the translated message.
+    fn l10n_key(&self) -> &str;
+
+    /// The arguments replacing the `{name}` placeholders of the translated message.
+    fn args(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+/// The localization key and arguments of an alert, from its [`LocalizableError`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlertL10n {
+    /// The key looked up in the [`AlertLocalizer`].
+    pub key: String,
+    /// The arguments of the translated message.
+    pub args: Vec<(String, String)>,
+}
+
+impl AlertL10n {
+    /// The key and arguments of `error`.
+    pub fn of(error: &impl LocalizableError) -> Self {
+        Self {
+            key: error.l10n_key().to_owned(),
+            args: error
+                .args()
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        }
+    }
+}
+
+/// The translated text an alert shows instead of its [`AlertMessage`](crate::AlertMessage),
+/// inserted when the [`AlertLocalizer`] translated it.
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
+pub struct LocalizedMessage(pub String);
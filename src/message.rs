@@ -0,0 +1,138 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::VecDeque,
+    fmt::{Display, Write},
+    sync::Arc,
+};
+
+/// A message that can be raised as an alert with [`Alerts::extend`](crate::Alerts::extend).
+///
+/// Static and owned strings are moved in without formatting; `format_args!` output is only
+/// formatted if it isn't a plain string literal. A system raising one of its recent messages
+/// again shares the earlier allocation, so a repeating error doesn't allocate its message again.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn check_disk(mut alerts: Alerts) {
+///     alerts.extend(["disk almost full"]);
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Update, check_disk);
+/// ```
+pub trait ToAlertMessage {
+    /// Converts the message, borrowing it for as long as possible.
+    fn to_alert_message(self) -> Cow<'static, str>;
+}
+
+impl ToAlertMessage for &'static str {
+    fn to_alert_message(self) -> Cow<'static, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl ToAlertMessage for String {
+    fn to_alert_message(self) -> Cow<'static, str> {
+        Cow::Owned(self)
+    }
+}
+
+impl ToAlertMessage for Box<str> {
+    fn to_alert_message(self) -> Cow<'static, str> {
+        Cow::Owned(self.into_string())
+    }
+}
+
+impl ToAlertMessage for Cow<'static, str> {
+    fn to_alert_message(self) -> Cow<'static, str> {
+        self
+    }
+}
+
+impl ToAlertMessage for std::fmt::Arguments<'_> {
+    fn to_alert_message(self) -> Cow<'static, str> {
+        match self.as_str() {
+            Some(message) => Cow::Borrowed(message),
+            None => Cow::Owned(self.to_string()),
+        }
+    }
+}
+
+/// A message converted by [`Alerts`](crate::Alerts) through its [`MessageCache`].
+pub(crate) trait IntoMessage {
+    fn into_message(self, cache: &mut MessageCache) -> Arc<str>;
+}
+
+impl<T: ToAlertMessage> IntoMessage for T {
+    fn into_message(self, cache: &mut MessageCache) -> Arc<str> {
+        cache.intern(&self.to_alert_message())
+    }
+}
+
+impl IntoMessage for Arc<str> {
+    fn into_message(self, _: &mut MessageCache) -> Arc<str> {
+        self
+    }
+}
+
+/// The `Display` output of a value, formatted without allocating if it was raised recently.
+pub(crate) struct Formatted<'a>(pub(crate) &'a dyn Display);
+
+impl IntoMessage for Formatted<'_> {
+    fn into_message(self, cache: &mut MessageCache) -> Arc<str> {
+        cache.format(self.0)
+    }
+}
+
+/// The `Display` output of an error, like [`Formatted`], but `String` errors are used as the
+/// message as they are instead of being formatted again.
+pub(crate) struct ErrorMessage<'a, E>(pub(crate) &'a E);
+
+impl<E: Display + 'static> IntoMessage for ErrorMessage<'_, E> {
+    fn into_message(self, cache: &mut MessageCache) -> Arc<str> {
+        match (self.0 as &dyn Any).downcast_ref::<String>() {
+            Some(message) => cache.intern(message),
+            None => cache.format(self.0),
+        }
+    }
+}
+
+/// The messages raised recently by a system, shared by the alerts raised with them again.
+#[derive(Debug, Default)]
+pub(crate) struct MessageCache {
+    recent: VecDeque<Arc<str>>,
+    buffer: String,
+}
+
+impl MessageCache {
+    /// The number of messages remembered.
+    const CAPACITY: usize = 16;
+
+    /// The shared message equal to `message`, allocated if it wasn't raised recently.
+    pub(crate) fn intern(&mut self, message: &str) -> Arc<str> {
+        if let Some(index) = self.recent.iter().position(|recent| **recent == *message) {
+            let recent = self.recent.remove(index).expect("the index was just found");
+            self.recent.push_front(recent.clone());
+            return recent;
+        }
+        if self.recent.len() == Self::CAPACITY {
+            self.recent.pop_back();
+        }
+        let message: Arc<str> = Arc::from(message);
+        self.recent.push_front(message.clone());
+        message
+    }
+
+    /// The shared `Display` output of `message`, formatted into a reused buffer.
+    pub(crate) fn format(&mut self, message: impl Display) -> Arc<str> {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        buffer.clear();
+        // writing to a `String` only fails if `message` does
+        let _ = write!(buffer, "{message}");
+        let message = self.intern(&buffer);
+        self.buffer = buffer;
+        message
+    }
+}
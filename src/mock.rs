@@ -0,0 +1,32 @@
+use bevy_ecs::prelude::*;
+
+use crate::{AlertBackend, AlertQueue, RaisedAlert};
+
+/// An [`AlertBackend`] recording every alert in [`MockAlerts`], installed by
+/// [`AlertsPlugin::headless`](crate::AlertsPlugin::headless).
+///
+/// The alerts are still queued and spawned as [`Alert`](bevy_ui_mod_alerts::Alert) entities,
+/// deduplicated and expired like shown alerts, but the headless plugin doesn't build their UI.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MockAlertBackend;
+
+impl AlertBackend for MockAlertBackend {
+    fn handle(&self, alerts: Vec<RaisedAlert>, commands: &mut Commands) {
+        commands.add(move |world: &mut World| {
+            let mut mock = world.get_resource_or_insert_with(MockAlerts::default);
+            mock.messages
+                .extend(alerts.iter().map(|alert| alert.message.to_string()));
+            AlertQueue::push(world, alerts);
+        });
+    }
+}
+
+/// The messages of the alerts raised through the [`MockAlertBackend`], oldest first.
+///
+/// Every raised message is recorded, including those folded into an existing alert by
+/// [`AlertConfig::dedup`](crate::AlertConfig::dedup).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Resource)]
+pub struct MockAlerts {
+    /// The recorded messages.
+    pub messages: Vec<String>,
+}
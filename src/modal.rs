@@ -0,0 +1,42 @@
+use bevy_ecs::prelude::*;
+
+/// Marks an alert shown as a modal, centered over a [`ModalScrim`] blocking the rest of the UI
+/// until the user presses its "OK" button.
+///
+/// See [`AnyhowAlertExt::anyhow_alert_modal`](crate::AnyhowAlertExt::anyhow_alert_modal).
+#[derive(Clone, Copy, Debug, Default, Component)]
+pub struct ModalAlert;
+
+/// Marks the node shown behind the [`ModalAlert`]s, covering the window.
+///
+/// All the modal alerts share one scrim, which is despawned once none of them is shown.
+#[derive(Clone, Copy, Debug, Default, Component)]
+pub struct ModalScrim;
+
+/// The "OK" button of the [`ModalAlert`] `alert`.
+#[cfg(feature = "ui")]
+#[derive(Debug, Component)]
+pub struct ModalOkButton {
+    pub(crate) alert: Entity,
+}
+
+/// Whether a [`ModalAlert`] is shown and not yet dismissed.
+///
+/// See [`modal_alert_active`] to pause game systems while it is set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Resource)]
+pub struct ModalAlertActive(pub bool);
+
+/// A run condition returning whether a [`ModalAlert`] is shown.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn move_player() { /* ... */ }
+///
+/// let mut app = App::new();
+/// app.add_systems(Update, move_player.run_if(not(modal_alert_active)));
+/// ```
+pub fn modal_alert_active(active: Option<Res<ModalAlertActive>>) -> bool {
+    active.is_some_and(|active| active.0)
+}
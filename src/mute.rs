@@ -0,0 +1,26 @@
+use bevy_ecs::prelude::*;
+
+use crate::Alerts;
+
+/// The number of alerts suppressed since [`AlertConfig::muted`](crate::AlertConfig::muted) was
+/// set, inserted by [`AlertsPlugin`](crate::AlertsPlugin).
+///
+/// Reset once the alerts are unmuted, after showing the summary enabled by
+/// [`AlertConfig::mute_summary`](crate::AlertConfig::mute_summary).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Resource)]
+pub struct SuppressedAlerts(pub usize);
+
+impl SuppressedAlerts {
+    /// The message of the summary shown once the alerts are unmuted.
+    pub fn summary(&self) -> String {
+        match self.0 {
+            1 => "1 alert suppressed".to_string(),
+            count => format!("{count} alerts suppressed"),
+        }
+    }
+}
+
+/// Raises the summary of the suppressed alerts once they are unmuted.
+pub(crate) fn summarize_suppressed_alerts(mut alerts: Alerts) {
+    alerts.summarize_suppressed();
+}
@@ -0,0 +1,209 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, world::Command};
+use bevy_time::{Real, Time};
+use bevy_utils::tracing::{info, warn};
+
+use crate::{AlertHistory, AlertHistoryEntry, LOG_TARGET};
+
+/// The file the [`AlertHistory`] is kept in across runs, see
+/// [`AlertsPlugin::persist_history`](crate::AlertsPlugin::persist_history).
+///
+/// The file holds one RON [`AlertHistoryEntry`] per line, so new entries are appended to it.
+#[derive(Clone, Debug, Resource)]
+pub struct PersistedHistory {
+    /// The file the history is loaded from on startup and appended to.
+    pub path: PathBuf,
+    /// How often the new entries are appended while alerts are recorded. Defaults to
+    /// [`PersistedHistory::DEFAULT_FLUSH_INTERVAL`].
+    ///
+    /// They are also appended when the app exits, and by [`FlushAlertHistory`].
+    pub flush_interval: Duration,
+}
+
+impl PersistedHistory {
+    /// The default [`PersistedHistory::flush_interval`].
+    pub const DEFAULT_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+    /// Keeps the history in the file at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            flush_interval: Self::DEFAULT_FLUSH_INTERVAL,
+        }
+    }
+}
+
+/// A [`Command`] that appends the [`AlertHistory`] entries recorded since the last write to the
+/// [`PersistedHistory`] file right away.
+///
+/// Failing to write the file is logged as a warning, and the entries are written by the next
+/// flush instead.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FlushAlertHistory;
+
+impl Command for FlushAlertHistory {
+    fn apply(self, world: &mut World) {
+        let (Some(persisted), Some(history)) = (
+            world.get_resource::<PersistedHistory>(),
+            world.get_resource::<AlertHistory>(),
+        ) else {
+            return;
+        };
+        let written = world
+            .get_resource::<WrittenHistory>()
+            .copied()
+            .unwrap_or_default();
+        let new = (history.recorded() - written.recorded) as usize;
+        let current = history
+            .iter()
+            .rev()
+            .take_while(|entry| !entry.previous_session)
+            .count();
+        // the latest entry written before is written again once more alerts are folded into it
+        let folded = written.recorded > 0
+            && new < current
+            && history
+                .iter()
+                .nth_back(new)
+                .is_some_and(|entry| entry.count != written.count);
+        let unwritten = new.min(current) + usize::from(folded);
+        if unwritten == 0 {
+            return;
+        }
+        let lines = to_lines(history.latest(unwritten));
+        let written = WrittenHistory {
+            recorded: history.recorded(),
+            count: history.iter().next_back().map_or(0, |entry| entry.count),
+        };
+        let path = persisted.path.clone();
+        match append(&path, &lines) {
+            Ok(()) => world.insert_resource(written),
+            Err(error) => warn!(
+                target: LOG_TARGET,
+                "couldn't append to the alert history at {}: {error}",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// What was last appended to the [`PersistedHistory`] file.
+#[derive(Clone, Copy, Debug, Default, Resource)]
+struct WrittenHistory {
+    /// The [`AlertHistory::recorded`] entries at the last write.
+    recorded: u64,
+    /// The count of the latest entry at the last write.
+    count: usize,
+}
+
+/// Serializes `entries` as one RON entry per line.
+fn to_lines<'a>(entries: impl Iterator<Item = &'a AlertHistoryEntry>) -> String {
+    let mut lines = String::new();
+    for entry in entries {
+        let line = ron::ser::to_string(entry)
+            .expect("the entries of the alert history are always serializable");
+        lines.push_str(&line);
+        lines.push('\n');
+    }
+    lines
+}
+
+fn append(path: &Path, lines: &str) -> std::io::Result<()> {
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(lines.as_bytes())
+}
+
+pub(crate) fn persist_history(app: &mut App, path: PathBuf) {
+    if cfg!(target_arch = "wasm32") {
+        warn!(
+            target: LOG_TARGET,
+            "the alert history can't be persisted on the web, so it starts empty on every run"
+        );
+        return;
+    }
+    app.insert_resource(PersistedHistory::new(path))
+        .init_resource::<WrittenHistory>()
+        .add_systems(PreStartup, load_persisted_history)
+        .add_systems(Last, flush_persisted_history);
+}
+
+/// Loads the entries of the [`PersistedHistory`] file as entries of a previous session, then
+/// rewrites the file with the loaded entries so it doesn't grow past the history's capacity.
+///
+/// A missing or unreadable file is logged, and the history starts empty. Corrupt lines, such as
+/// one cut short by a crash, are logged and skipped.
+fn load_persisted_history(mut history: ResMut<AlertHistory>, persisted: Res<PersistedHistory>) {
+    let path = persisted.path.display();
+    let ron = match std::fs::read_to_string(&persisted.path) {
+        Ok(ron) => ron,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            info!(target: LOG_TARGET, "no alert history at {path}, starting a new one");
+            return;
+        }
+        Err(error) => {
+            warn!(target: LOG_TARGET, "couldn't read the alert history at {path}: {error}");
+            return;
+        }
+    };
+    let mut entries = Vec::<AlertHistoryEntry>::new();
+    let mut corrupt = 0;
+    for line in ron.lines().filter(|line| !line.trim().is_empty()) {
+        let Ok(entry) = ron::from_str::<AlertHistoryEntry>(line) else {
+            corrupt += 1;
+            continue;
+        };
+        // an entry written again after more alerts were folded into it replaces its first copy
+        match entries.last_mut() {
+            Some(last) if is_rewritten(last, &entry) => *last = entry,
+            _ => entries.push(entry),
+        }
+    }
+    if corrupt > 0 {
+        warn!(
+            target: LOG_TARGET,
+            "skipped {corrupt} corrupt lines of the alert history at {path}"
+        );
+    }
+    history.restore(entries);
+    if let Err(error) = std::fs::write(&persisted.path, to_lines(history.iter())) {
+        warn!(target: LOG_TARGET, "couldn't compact the alert history at {path}: {error}");
+    }
+}
+
+/// Whether `entry` is `previous` written again by the same run.
+fn is_rewritten(previous: &AlertHistoryEntry, entry: &AlertHistoryEntry) -> bool {
+    // the loaded entries are written back flagged, so entries of different runs never match
+    !previous.previous_session
+        && !entry.previous_session
+        && previous.raised_at == entry.raised_at
+        && previous.frame == entry.frame
+        && previous.message == entry.message
+}
+
+fn flush_persisted_history(
+    mut commands: Commands,
+    history: Res<AlertHistory>,
+    persisted: Res<PersistedHistory>,
+    time: Res<Time<Real>>,
+    mut exits: EventReader<AppExit>,
+    mut unsaved: Local<bool>,
+    mut since_flush: Local<Duration>,
+) {
+    *unsaved |= history.is_changed();
+    *since_flush += time.delta();
+    let exiting = exits.read().next().is_some();
+    if *unsaved && (exiting || *since_flush >= persisted.flush_interval) {
+        commands.add(FlushAlertHistory);
+        *unsaved = false;
+        *since_flush = Duration::ZERO;
+    }
+}
@@ -0,0 +1,1597 @@
+use std::{sync::Arc, time::Duration};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+#[cfg(not(feature = "ui"))]
+use crate::RaisedAlert;
+use crate::{
+    alert_channel, raise_sent_alerts, register_error_events, summarize_suppressed_alerts,
+    ActiveAlertBackend, AlertBackend, AlertChannels, AlertDismissed, AlertHistory, AlertRaised,
+    AlertRepeated, AlertReport, AlertReporters, Alerts, ChannelConfig, ErrorRaised, ErrorStats,
+    ModalAlertActive, Severity, SuppressedAlerts,
+};
+#[cfg(feature = "ui")]
+use std::{borrow::Cow, cmp::Reverse, collections::HashMap};
+
+#[cfg(feature = "ui")]
+use crate::{
+    clear_on_state_change, ActiveClipboard, AlertActionButton, AlertActions, AlertAge,
+    AlertAnimation, AlertBuilder, AlertChannel, AlertChannelRoot, AlertClipboard, AlertCount,
+    AlertEffect, AlertIcon, AlertLocalizer, AlertMessage, AlertPhase, AlertPriority, AlertQueue,
+    AlertStage, AlertStyle, AlertStyleRegistry, AlertTheme, ClearAlerts, CopyButton, DismissReason,
+    LiveAlerts, LocalizedMessage, ModalAlert, ModalOkButton, ModalScrim, RaisedAt, SeverityIcon,
+    ShownOrder, Sticky, Ttl, TtlBar, LOG_TARGET,
+};
+#[cfg(feature = "ui")]
+use bevy_color::{palettes::css, Alpha, Color};
+#[cfg(feature = "ui")]
+use bevy_core::Name;
+#[cfg(feature = "ui")]
+use bevy_ecs::{
+    entity::Entities,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+#[cfg(feature = "ui")]
+use bevy_hierarchy::{BuildChildren, ChildBuilder, Children, DespawnRecursiveExt, Parent};
+#[cfg(feature = "ui")]
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+#[cfg(feature = "ui")]
+use bevy_text::{Text, TextStyle};
+#[cfg(feature = "ui")]
+use bevy_time::{Real, Time};
+#[cfg(feature = "ui")]
+use bevy_ui::{
+    node_bundles::{ButtonBundle, ImageBundle, NodeBundle, TextBundle},
+    AlignItems, AlignSelf, BackgroundColor, BorderRadius, Display, FlexDirection, FocusPolicy,
+    Interaction, JustifyContent, Node, PositionType, Style, UiImage, UiRect, Val, ZIndex,
+};
+#[cfg(feature = "ui")]
+use bevy_ui_mod_alerts::{
+    Alert, AlertElements, AlertLifetime, AlertMarker, AlertSystems, AlertTransition, AlertUi,
+    AlertUiRoot, DismissButton, MaxAlerts, TransitionTimer,
+};
+#[cfg(feature = "ui")]
+use bevy_utils::tracing::warn;
+
+/// A Bevy plugin that must be attached in order to spawn alert UIs.
+///
+#[cfg_attr(
+    feature = "ui",
+    doc = "This adds the [`bevy_ui_mod_alerts::AlertsPlugin`] that renders the alerts, along \
+           with the bookkeeping used by the alert pipes in this crate."
+)]
+#[cfg_attr(
+    not(feature = "ui"),
+    doc = "This adds the bookkeeping used by the alert pipes in this crate."
+)]
+#[derive(Debug, Default)]
+pub struct AlertsPlugin {
+    error_events: Vec<fn(&mut World)>,
+    backend: Option<Arc<dyn AlertBackend>>,
+    #[cfg(feature = "ui")]
+    theme: Option<AlertTheme>,
+    #[cfg(feature = "ui")]
+    builder: Option<AlertBuilder>,
+    #[cfg(feature = "ui")]
+    clipboard: Option<Arc<dyn AlertClipboard>>,
+    #[cfg(feature = "ui")]
+    headless: bool,
+    #[cfg(feature = "ui")]
+    clear_schedules: Vec<InternedScheduleLabel>,
+    channels: Vec<(String, ChannelConfig)>,
+    reporters: AlertReporters,
+    #[cfg(feature = "serde")]
+    persisted_history: Option<std::path::PathBuf>,
+    #[cfg(feature = "file_log")]
+    file_log: Option<(std::path::PathBuf, u64)>,
+}
+
+impl AlertsPlugin {
+    /// Builds a default AlertsPlugin.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds an AlertsPlugin for headless tests, recording alerts with the
+    /// [`MockAlertBackend`](crate::MockAlertBackend) instead of building their UI.
+    ///
+    /// The alerts are still spawned as [`Alert`](bevy_ui_mod_alerts::Alert) entities, without
+    /// nodes, text or images, and are deduplicated and expire as usual, so tests can run with
+    /// `MinimalPlugins` alone. Dismiss buttons, animations, and the other UI features are not
+    /// added.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, AlertsPlugin::headless()));
+    /// ```
+    #[cfg(feature = "ui")]
+    pub fn headless() -> Self {
+        Self {
+            headless: true,
+            backend: Some(Arc::new(crate::MockAlertBackend)),
+            ..Self::default()
+        }
+    }
+
+    /// Presents alerts with `backend` instead of the default [`ActiveAlertBackend`].
+    pub fn with_backend(mut self, backend: impl AlertBackend) -> Self {
+        self.backend = Some(Arc::new(backend));
+        self
+    }
+
+    /// Shows alerts with `theme` instead of the default [`AlertTheme`].
+    #[cfg(feature = "ui")]
+    pub fn with_theme(mut self, theme: AlertTheme) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Builds the body of every alert with `builder` instead of showing its message as text.
+    ///
+    /// The body is the node below the dismiss button. The alerts still expire, stack, and get
+    /// dismissed as usual, but the [`AlertTheme`] text style and the [`AlertCount`] suffix only
+    /// apply to the default text.
+    #[cfg(feature = "ui")]
+    pub fn with_builder(
+        mut self,
+        builder: impl Fn(&mut ChildBuilder, &AlertMessage) + Send + Sync + 'static,
+    ) -> Self {
+        self.builder = Some(AlertBuilder(Arc::new(builder)));
+        self
+    }
+
+    /// Copies alert texts to `clipboard` instead of the default [`LogClipboard`](crate::LogClipboard).
+    #[cfg(feature = "ui")]
+    pub fn with_clipboard(mut self, clipboard: impl AlertClipboard) -> Self {
+        self.clipboard = Some(Arc::new(clipboard));
+        self
+    }
+
+    /// Dismisses every shown and queued alert with [`DismissReason::StateChanged`] whenever
+    /// `schedule` runs, and resets their [`AlertConfig::dedup`] counts, so the next alerts start
+    /// over. Without it, alerts are kept across state changes.
+    ///
+    /// Pass the `OnExit` schedule of a state, like `OnExit(GameState::Menu)`, to keep the alerts
+    /// raised in that state from lingering into the next one. Call it once for each state to
+    /// clear, of one or several state types.
+    #[cfg(feature = "ui")]
+    pub fn clear_in(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.clear_schedules.push(schedule.intern());
+        self
+    }
+
+    /// Registers a channel of alerts shown in their own stack, see
+    /// [`AnyhowAlertExt::anyhow_alert_channel`](crate::AnyhowAlertExt::anyhow_alert_channel).
+    ///
+    #[cfg_attr(
+        feature = "ui",
+        doc = "The alerts of the channel are children of an [`AlertChannelRoot`] node anchored \
+               according to the theme of `config`."
+    )]
+    /// The other [`AlertConfig`] settings apply to them too.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// let network = ChannelConfig {
+    /// #   #[cfg(feature = "ui")]
+    ///     theme: AlertTheme {
+    ///         anchor: AlertAnchor::BottomLeft,
+    ///         ..default()
+    ///     },
+    ///     max_visible: Some(5),
+    /// };
+    /// let mut app = App::new();
+    /// app.add_plugins(AlertsPlugin::new().with_channel("network", network));
+    /// ```
+    pub fn with_channel(mut self, name: impl Into<String>, config: ChannelConfig) -> Self {
+        self.channels.push((name.into(), config));
+        self
+    }
+
+    /// Keeps the [`AlertHistory`] in the file at `path` across runs of the app, such as for an
+    /// operator to see the errors raised before a restart.
+    ///
+    /// The entries written by the previous runs are loaded on startup, flagged as
+    /// [`previous_session`](crate::AlertHistoryEntry::previous_session). The entries recorded
+    /// since are appended to the file every
+    /// [`PersistedHistory::flush_interval`](crate::PersistedHistory::flush_interval) while alerts
+    /// are recorded, when the app exits, and by [`FlushAlertHistory`](crate::FlushAlertHistory).
+    /// A missing file is logged, and the history starts empty.
+    ///
+    /// On startup, the file is rewritten with the loaded entries, so it holds at most
+    /// [`AlertHistory::capacity`] entries from the previous runs.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(AlertsPlugin::new().persist_history("alert_history.ron"));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn persist_history(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.persisted_history = Some(path.into());
+        self
+    }
+
+    /// Appends every alert to the file at `path`, rotating it once it would grow past
+    /// `max_size` bytes, so players can send the errors they missed. See
+    /// [`FileAlertSink`](crate::FileAlertSink).
+    ///
+    /// Requires the `file_log` feature. Does nothing but warn on the web.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(
+    ///     AlertsPlugin::new().log_to_file(FileAlertSink::next_to_executable(), 1024 * 1024),
+    /// );
+    /// ```
+    #[cfg(feature = "file_log")]
+    pub fn log_to_file(mut self, path: impl Into<std::path::PathBuf>, max_size: u64) -> Self {
+        self.file_log = Some((path.into(), max_size));
+        self
+    }
+
+    /// Calls `callback` for every alert shown, and every repeated message folded into a shown
+    /// alert by [`AlertConfig::dedup`], such as to forward errors to a telemetry service.
+    ///
+    /// The callback runs after the alerts are filtered and deduplicated, on the frame they are
+    /// shown. It is called on the main thread, so it should only hand the report over, for
+    /// example to a channel read by another thread; use [`AlertsPlugin::on_alert_in_background`]
+    /// for heavier work. Without the `ui` feature, it is called for every alert logged by the
+    /// [`LogAlertBackend`](crate::LogAlertBackend).
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    ///
+    /// use bevy::prelude::*;
+    /// use bevy_anyhow_alert::*;
+    ///
+    /// let (reports, telemetry) = mpsc::channel();
+    /// let mut app = App::new();
+    /// app.add_plugins(AlertsPlugin::new().on_alert(move |report: &AlertReport| {
+    ///     if report.severity == Severity::Error {
+    ///         let _ = reports.send(report.clone());
+    ///     }
+    /// }));
+    /// # drop(telemetry);
+    /// ```
+    pub fn on_alert(mut self, callback: impl Fn(&AlertReport) + Send + Sync + 'static) -> Self {
+        self.reporters.add(callback, false);
+        self
+    }
+
+    /// Like [`AlertsPlugin::on_alert`], but calls `callback` in a task of the
+    /// [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool), so it can't stall the frame.
+    ///
+    /// The reports of different alerts may be handled in any order.
+    pub fn on_alert_in_background(
+        mut self,
+        callback: impl Fn(&AlertReport) + Send + Sync + 'static,
+    ) -> Self {
+        self.reporters.add(callback, true);
+        self
+    }
+
+    /// Registers [`ErrorEvent<E>`](crate::ErrorEvent) when the plugin is built.
+    ///
+    /// Emitting an error registers its event type automatically, but systems reading
+    /// `EventReader<ErrorEvent<E>>` need it registered before the first error is emitted.
+    pub fn with_error_events<E: Send + Sync + 'static>(mut self) -> Self {
+        self.error_events.push(register_error_events::<E>);
+        self
+    }
+
+    /// A PipeableSystem that accepts a vector of alert messages and spawns `Alert`s for each of them.
+    pub fn alert(In(messages): In<Vec<String>>, mut alerts: Alerts) {
+        alerts.raise(messages, Severity::Error);
+    }
+}
+
+#[cfg(feature = "ui")]
+impl AlertsPlugin {
+    fn clear_alerts(mut events: EventReader<ClearAlerts>, mut commands: Commands) {
+        if !events.is_empty() {
+            events.clear();
+            commands.add(ClearAlerts);
+        }
+    }
+
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
+    fn show_queued_alerts(
+        mut commands: Commands,
+        mut queue: ResMut<AlertQueue>,
+        mut live: ResMut<LiveAlerts>,
+        alerts: Query<
+            (
+                Entity,
+                Option<&AlertChannel>,
+                Option<&AlertPriority>,
+                Option<&ShownOrder>,
+                Option<&AlertMessage>,
+                Has<Sticky>,
+            ),
+            With<Alert>,
+        >,
+        mut dismissed: EventWriter<AlertDismissed>,
+        config: Res<AlertConfig>,
+        channels: Res<AlertChannels>,
+        localizer: Option<Res<AlertLocalizer>>,
+    ) {
+        if config.paused {
+            return;
+        }
+        let mut visible = HashMap::<Option<String>, usize>::new();
+        // the shown alerts that a higher priority alert may replace, per channel
+        let mut evictable = HashMap::<Option<String>, Vec<_>>::new();
+        for (entity, channel, priority, order, message, sticky) in &alerts {
+            let channel = channel.map(|channel| channel.0.clone());
+            *visible.entry(channel.clone()).or_default() += 1;
+            if let (Some(priority), Some(order), Some(message), false) =
+                (priority, order, message, sticky)
+            {
+                let evictable = evictable.entry(channel).or_default();
+                evictable.push((priority.0, *order, entity, message.0.clone()));
+            }
+        }
+        let queued = std::mem::take(&mut queue.alerts);
+        for queued in queued {
+            let max_visible = queued
+                .channel
+                .as_ref()
+                .and_then(|channel| channels.0.get(channel))
+                .map_or(config.max_visible, |channel| channel.max_visible);
+            let visible = visible.entry(queued.channel.clone()).or_default();
+            if *visible >= max_visible.unwrap_or(usize::MAX) {
+                // replace the lowest priority alert shown, the oldest one on ties
+                let shown = evictable.entry(queued.channel.clone()).or_default();
+                let lowest = (0..shown.len()).min_by_key(|at| (shown[*at].0, shown[*at].1));
+                let Some(lowest) = lowest.filter(|at| shown[*at].0 < queued.priority) else {
+                    queue.alerts.push_back(queued);
+                    continue;
+                };
+                let (_, _, entity, message) = shown.swap_remove(lowest);
+                commands.entity(entity).despawn_recursive();
+                live.alerts.retain(|_, (alert, _)| *alert != entity);
+                dismissed.send(AlertDismissed {
+                    entity,
+                    message,
+                    reason: DismissReason::Evicted,
+                });
+            } else {
+                *visible += 1;
+            }
+            let order = ShownOrder(queue.shown);
+            queue.shown += 1;
+            let key = queued.key();
+            let count = queued.count;
+            let entity = queued.spawn(&mut commands, order, localizer.as_deref());
+            if config.dedup {
+                live.alerts.insert(key, (entity, count));
+            }
+        }
+    }
+
+    fn expire_alerts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (Entity, &Ttl, &mut AlertAge, &mut AlertPhase, &AlertMessage),
+            With<AlertUi>,
+        >,
+        mut dismissed: EventWriter<AlertDismissed>,
+        time: Res<Time<Real>>,
+    ) {
+        for (entity, ttl, mut age, mut phase, message) in &mut alerts {
+            let expired = AlertDismissed {
+                entity,
+                message: message.0.clone(),
+                reason: DismissReason::Expired,
+            };
+            if ttl.0.is_zero() {
+                commands.entity(entity).despawn_recursive();
+                dismissed.send(expired);
+                continue;
+            }
+            if phase.stage != AlertStage::Shown {
+                continue;
+            }
+            age.0 += time.delta();
+            if age.0 > ttl.0 {
+                commands.entity(entity).remove::<AlertAge>();
+                phase.start(AlertStage::Exiting);
+                dismissed.send(expired);
+            }
+        }
+    }
+
+    /// Despawns the alerts of [`AlertsPlugin::headless`] once their [`Ttl`] runs out.
+    fn expire_headless_alerts(
+        mut commands: Commands,
+        mut alerts: Query<(Entity, &Ttl, &mut AlertAge, &AlertMessage)>,
+        mut dismissed: EventWriter<AlertDismissed>,
+        time: Res<Time<Real>>,
+    ) {
+        for (entity, ttl, mut age, message) in &mut alerts {
+            age.0 += time.delta();
+            if age.0 > ttl.0 {
+                commands.entity(entity).despawn_recursive();
+                dismissed.send(AlertDismissed {
+                    entity,
+                    message: message.0.clone(),
+                    reason: DismissReason::Expired,
+                });
+            }
+        }
+    }
+
+    fn remove_dismiss_buttons(
+        mut commands: Commands,
+        buttons: Query<(Entity, &Parent), Added<DismissButton>>,
+        config: Res<AlertConfig>,
+    ) {
+        if config.dismiss_button {
+            return;
+        }
+        for (button, header) in &buttons {
+            commands.entity(header.get()).remove_children(&[button]);
+            commands.entity(button).despawn_recursive();
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn dismiss_pressed_alerts(
+        buttons: Query<(&Interaction, &Parent), (Changed<Interaction>, With<DismissButton>)>,
+        headers: Query<&Parent>,
+        mut alerts: Query<(&mut AlertPhase, &AlertMessage)>,
+        mut dismissed: EventWriter<AlertDismissed>,
+    ) {
+        for (interaction, header) in &buttons {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            let Ok(entity) = headers.get(header.get()).map(Parent::get) else {
+                continue;
+            };
+            let Ok((mut phase, message)) = alerts.get_mut(entity) else {
+                continue;
+            };
+            if phase.exit() {
+                dismissed.send(AlertDismissed {
+                    entity,
+                    message: message.0.clone(),
+                    reason: DismissReason::User,
+                });
+            }
+        }
+    }
+
+    fn make_alerts_clickable(
+        mut commands: Commands,
+        alerts: Query<(Entity, Has<Interaction>), With<AlertUi>>,
+        config: Res<AlertConfig>,
+    ) {
+        for (alert, clickable) in &alerts {
+            if config.click_to_dismiss && !clickable {
+                commands
+                    .entity(alert)
+                    .insert((Interaction::default(), FocusPolicy::Block));
+            } else if !config.click_to_dismiss && clickable {
+                commands
+                    .entity(alert)
+                    .remove::<Interaction>()
+                    .insert(FocusPolicy::Pass);
+            }
+        }
+    }
+
+    fn dismiss_clicked_alerts(
+        mut alerts: Query<
+            (Entity, &Interaction, &mut AlertPhase, &AlertMessage),
+            Changed<Interaction>,
+        >,
+        mut dismissed: EventWriter<AlertDismissed>,
+    ) {
+        for (entity, interaction, mut phase, message) in &mut alerts {
+            if *interaction == Interaction::Pressed && phase.exit() {
+                dismissed.send(AlertDismissed {
+                    entity,
+                    message: message.0.clone(),
+                    reason: DismissReason::User,
+                });
+            }
+        }
+    }
+
+    fn dismiss_alerts_by_hotkey(
+        mut alerts: Query<(Entity, &RaisedAt, &mut AlertPhase, &AlertMessage)>,
+        mut dismissed: EventWriter<AlertDismissed>,
+        keys: Option<Res<ButtonInput<KeyCode>>>,
+        config: Res<AlertConfig>,
+    ) {
+        let Some(keys) = keys else {
+            return;
+        };
+        let pressed = |hotkey: Option<Hotkey>| hotkey.is_some_and(|key| key.just_pressed(&keys));
+        let mut dismiss = |entity: Entity, phase: &mut AlertPhase, message: &AlertMessage| {
+            if phase.exit() {
+                dismissed.send(AlertDismissed {
+                    entity,
+                    message: message.0.clone(),
+                    reason: DismissReason::User,
+                });
+            }
+        };
+        if pressed(config.hotkeys.dismiss_all) {
+            for (alert, _, mut phase, message) in &mut alerts {
+                dismiss(alert, &mut phase, message);
+            }
+        } else if pressed(config.hotkeys.dismiss_latest) {
+            let latest = alerts
+                .iter_mut()
+                .filter(|(_, _, phase, _)| phase.stage != AlertStage::Exiting)
+                .max_by_key(|(alert, raised_at, _, _)| {
+                    (raised_at.real_time, raised_at.frame, *alert)
+                });
+            if let Some((alert, _, mut phase, message)) = latest {
+                dismiss(alert, &mut phase, message);
+            }
+        }
+    }
+
+    fn add_copy_buttons(
+        mut commands: Commands,
+        alerts: Query<(Entity, &Children), Added<AlertUi>>,
+        names: Query<&Name>,
+        config: Res<AlertConfig>,
+    ) {
+        if !config.copy_button {
+            return;
+        }
+        for (alert, alert_children) in &alerts {
+            let header = alert_children.iter().find(|child| {
+                names
+                    .get(**child)
+                    .is_ok_and(|name| name.as_str() == "Alert Header UI")
+            });
+            let Some(header) = header else {
+                continue;
+            };
+            let button = commands
+                .spawn((
+                    Name::new("Copy Button"),
+                    ButtonBundle {
+                        style: Style {
+                            height: Val::Percent(100.),
+                            padding: UiRect::horizontal(Val::Px(4.)),
+                            align_items: AlignItems::Center,
+                            ..Default::default()
+                        },
+                        background_color: Color::Srgba(css::DARK_GRAY).into(),
+                        ..Default::default()
+                    },
+                    CopyButton { alert },
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        "Copy",
+                        TextStyle {
+                            font_size: 14.,
+                            color: Color::WHITE,
+                            ..Default::default()
+                        },
+                    ));
+                })
+                .id();
+            // before the dismiss button
+            commands.entity(*header).insert_children(0, &[button]);
+        }
+    }
+
+    fn copy_pressed_alerts(
+        buttons: Query<(&Interaction, &CopyButton), Changed<Interaction>>,
+        alerts: Query<(&AlertMessage, &RaisedAt)>,
+        clipboard: Res<ActiveClipboard>,
+    ) {
+        for (interaction, button) in &buttons {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            if let Ok((message, raised_at)) = alerts.get(button.alert) {
+                clipboard
+                    .0
+                    .set_text(format!("{} {}", raised_at.timestamp(), message.0));
+            }
+        }
+    }
+
+    fn add_action_buttons(
+        mut commands: Commands,
+        alerts: Query<(Entity, &AlertActions), Added<AlertUi>>,
+    ) {
+        for (alert, actions) in &alerts {
+            let row = commands
+                .spawn((
+                    Name::new("Alert Actions UI"),
+                    NodeBundle {
+                        style: Style {
+                            padding: UiRect::all(Val::Px(4.)),
+                            column_gap: Val::Px(4.),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                ))
+                .with_children(|row| {
+                    for action in &actions.0 {
+                        row.spawn((
+                            Name::new("Action Button"),
+                            ButtonBundle {
+                                style: Style {
+                                    padding: UiRect::horizontal(Val::Px(4.)),
+                                    align_items: AlignItems::Center,
+                                    ..Default::default()
+                                },
+                                background_color: Color::Srgba(css::DARK_GRAY).into(),
+                                ..Default::default()
+                            },
+                            AlertActionButton {
+                                alert,
+                                system: action.system,
+                            },
+                        ))
+                        .with_children(|button| {
+                            button.spawn(TextBundle::from_section(
+                                action.label.clone(),
+                                TextStyle {
+                                    font_size: 14.,
+                                    color: Color::WHITE,
+                                    ..Default::default()
+                                },
+                            ));
+                        });
+                    }
+                })
+                .id();
+            commands.entity(alert).add_child(row);
+        }
+    }
+
+    fn run_pressed_actions(
+        mut commands: Commands,
+        buttons: Query<(&Interaction, &AlertActionButton), Changed<Interaction>>,
+        mut alerts: Query<(&mut AlertPhase, &AlertMessage)>,
+        mut dismissed: EventWriter<AlertDismissed>,
+    ) {
+        for (interaction, button) in &buttons {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            commands.run_system(button.system);
+            let Ok((mut phase, message)) = alerts.get_mut(button.alert) else {
+                continue;
+            };
+            if phase.exit() {
+                dismissed.send(AlertDismissed {
+                    entity: button.alert,
+                    message: message.0.clone(),
+                    reason: DismissReason::User,
+                });
+            }
+        }
+    }
+
+    /// Hides the buttons of actions whose system was removed.
+    fn hide_removed_actions(
+        mut buttons: Query<(&AlertActionButton, &mut Style)>,
+        entities: &Entities,
+    ) {
+        for (button, mut style) in &mut buttons {
+            let display = if entities.contains(button.system.entity()) {
+                Display::Flex
+            } else {
+                Display::None
+            };
+            if style.display != display {
+                style.display = display;
+            }
+        }
+    }
+
+    fn route_modal_alerts(
+        mut commands: Commands,
+        alerts: Query<Entity, (Added<AlertUi>, With<ModalAlert>)>,
+        scrims: Query<Entity, With<ModalScrim>>,
+    ) {
+        if alerts.is_empty() {
+            return;
+        }
+        let scrim = scrims.iter().next().unwrap_or_else(|| {
+            let node = NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(8.),
+                    ..Default::default()
+                },
+                background_color: Color::BLACK.with_alpha(0.6).into(),
+                // keeps the pointer from reaching the UI behind the scrim
+                focus_policy: FocusPolicy::Block,
+                z_index: ZIndex::Global(i32::MAX),
+                ..Default::default()
+            };
+            let name = Name::new("Modal Alert Scrim");
+            commands.spawn((ModalScrim, name, node)).id()
+        });
+        for alert in &alerts {
+            commands.entity(scrim).add_child(alert);
+        }
+    }
+
+    fn add_modal_ok_buttons(
+        mut commands: Commands,
+        alerts: Query<Entity, (Added<AlertUi>, With<ModalAlert>)>,
+    ) {
+        for alert in &alerts {
+            let button = commands
+                .spawn((
+                    Name::new("Modal OK Button"),
+                    ButtonBundle {
+                        style: Style {
+                            margin: UiRect::all(Val::Px(4.)),
+                            padding: UiRect::horizontal(Val::Px(8.)),
+                            align_self: AlignSelf::Center,
+                            ..Default::default()
+                        },
+                        background_color: Color::Srgba(css::DARK_GRAY).into(),
+                        ..Default::default()
+                    },
+                    ModalOkButton { alert },
+                ))
+                .with_children(|button| {
+                    button.spawn(TextBundle::from_section(
+                        "OK",
+                        TextStyle {
+                            font_size: 14.,
+                            color: Color::WHITE,
+                            ..Default::default()
+                        },
+                    ));
+                })
+                .id();
+            commands.entity(alert).add_child(button);
+        }
+    }
+
+    fn acknowledge_modal_alerts(
+        buttons: Query<(&Interaction, &ModalOkButton), Changed<Interaction>>,
+        mut alerts: Query<(&mut AlertPhase, &AlertMessage)>,
+        mut dismissed: EventWriter<AlertDismissed>,
+    ) {
+        for (interaction, button) in &buttons {
+            if *interaction != Interaction::Pressed {
+                continue;
+            }
+            let Ok((mut phase, message)) = alerts.get_mut(button.alert) else {
+                continue;
+            };
+            if phase.exit() {
+                dismissed.send(AlertDismissed {
+                    entity: button.alert,
+                    message: message.0.clone(),
+                    reason: DismissReason::User,
+                });
+            }
+        }
+    }
+
+    fn update_modal_alert_active(
+        mut commands: Commands,
+        alerts: Query<Option<&AlertPhase>, With<ModalAlert>>,
+        scrims: Query<Entity, With<ModalScrim>>,
+        mut active: ResMut<ModalAlertActive>,
+    ) {
+        let shown = alerts
+            .iter()
+            .any(|phase| phase.map_or(true, |phase| phase.stage != AlertStage::Exiting));
+        if active.0 != shown {
+            active.0 = shown;
+        }
+        if !shown {
+            // dismissed modal alerts are despawned with the scrim rather than animated out
+            for scrim in &scrims {
+                commands.entity(scrim).despawn_recursive();
+            }
+        }
+    }
+
+    fn add_ttl_bars(
+        mut commands: Commands,
+        alerts: Query<Entity, (Added<AlertUi>, With<Ttl>)>,
+        config: Res<AlertConfig>,
+    ) {
+        if !config.show_ttl_bar {
+            return;
+        }
+        for alert in &alerts {
+            let bar = commands
+                .spawn((
+                    Name::new("Alert TTL Bar"),
+                    TtlBar,
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(100.),
+                            height: Val::Px(3.),
+                            ..Default::default()
+                        },
+                        background_color: Color::Srgba(css::DARK_GRAY).into(),
+                        ..Default::default()
+                    },
+                ))
+                .id();
+            commands.entity(alert).add_child(bar);
+        }
+    }
+
+    fn shrink_ttl_bars(
+        mut bars: Query<(&Parent, &mut Style), With<TtlBar>>,
+        alerts: Query<(&Ttl, Option<&AlertAge>)>,
+    ) {
+        for (alert, mut style) in &mut bars {
+            let Ok((ttl, age)) = alerts.get(alert.get()) else {
+                continue;
+            };
+            // the age is removed once the alert expires
+            let left = age.map_or(0., |age| 1. - age.0.as_secs_f32() / ttl.0.as_secs_f32());
+            let width = Val::Percent(left.clamp(0., 1.) * 100.);
+            if style.width != width {
+                style.width = width;
+            }
+        }
+    }
+
+    /// Animates alerts instead of the upstream transitions, which are removed as they start.
+    #[allow(clippy::type_complexity)]
+    fn start_alert_animations(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                &AlertTransition,
+                Option<&AlertAnimation>,
+                Option<&mut AlertPhase>,
+                &BackgroundColor,
+                Option<&AlertChannel>,
+            ),
+            With<Alert>,
+        >,
+        theme: Res<AlertTheme>,
+        channels: Res<AlertChannels>,
+    ) {
+        for (entity, transition, animation, phase, background, channel) in &mut alerts {
+            let mut alert = commands.entity(entity);
+            alert.remove::<(AlertTransition, TransitionTimer)>();
+            match (transition, phase) {
+                (AlertTransition::FadeIn, None) => {
+                    alert.insert(AlertPhase {
+                        stage: AlertStage::Entering,
+                        elapsed: Duration::ZERO,
+                        alpha: background.0.alpha(),
+                    });
+                    if animation.is_none() {
+                        alert.insert(channels.theme(channel, &theme).animation);
+                    }
+                }
+                (AlertTransition::FadeOut, Some(mut phase))
+                    if phase.stage != AlertStage::Exiting =>
+                {
+                    phase.start(AlertStage::Exiting);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn animate_alerts(
+        mut commands: Commands,
+        mut alerts: Query<(
+            Entity,
+            &AlertAnimation,
+            &mut AlertPhase,
+            &mut BackgroundColor,
+            &mut Style,
+        )>,
+        time: Res<Time<Real>>,
+    ) {
+        for (entity, animation, mut phase, mut background, mut style) in &mut alerts {
+            let effect = match phase.stage {
+                AlertStage::Entering => animation.enter,
+                AlertStage::Shown => continue,
+                AlertStage::Exiting => animation.exit,
+            };
+            phase.elapsed += time.delta();
+            let done = effect == AlertEffect::None || phase.elapsed >= animation.duration;
+            let progress = if done {
+                1.
+            } else {
+                phase.elapsed.as_secs_f32() / animation.duration.as_secs_f32()
+            };
+            let visible = match phase.stage {
+                AlertStage::Exiting => 1. - progress,
+                _ => progress,
+            };
+            AlertAnimation::apply(effect, visible, &phase, &mut background, &mut style);
+            if done {
+                match phase.stage {
+                    AlertStage::Exiting => commands.entity(entity).despawn_recursive(),
+                    _ => phase.stage = AlertStage::Shown,
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn order_alerts(
+        mut roots: Query<&mut Children, Or<(With<AlertUiRoot>, With<AlertChannelRoot>)>>,
+        alerts: Query<(&ShownOrder, Has<Sticky>, Option<&AlertPriority>)>,
+        config: Res<AlertConfig>,
+    ) {
+        // sticky alerts first, then the highest priority, then in the order they were shown
+        let key = |alert: &Entity| match alerts.get(*alert) {
+            Ok((order, sticky, priority)) => (!sticky, Reverse(priority.copied()), Some(*order)),
+            Err(_) => (true, Reverse(None), None),
+        };
+        let compare = |a: &Entity, b: &Entity| {
+            let ((a_transient, a_priority, a_order), (b_transient, b_priority, b_order)) =
+                (key(a), key(b));
+            let by_age = match config.stack {
+                StackDirection::NewestOnBottom => a_order.cmp(&b_order),
+                StackDirection::NewestOnTop => b_order.cmp(&a_order),
+            };
+            a_transient
+                .cmp(&b_transient)
+                .then(a_priority.cmp(&b_priority))
+                .then(by_age)
+        };
+        for mut children in &mut roots {
+            // only sort when needed to keep change detection quiet
+            if !children
+                .windows(2)
+                .all(|pair| compare(&pair[0], &pair[1]).is_le())
+            {
+                children.sort_by(compare);
+            }
+        }
+    }
+
+    fn forget_despawned_alerts(
+        mut removed: RemovedComponents<Alert>,
+        mut live: ResMut<LiveAlerts>,
+    ) {
+        let removed = removed.read().collect::<Vec<_>>();
+        if !removed.is_empty() {
+            live.alerts
+                .retain(|_, (entity, _)| !removed.contains(entity));
+        }
+    }
+
+    fn route_channel_alerts(
+        mut commands: Commands,
+        alerts: Query<(Entity, &AlertChannel), Added<AlertUi>>,
+        roots: Query<(Entity, &AlertChannelRoot)>,
+        channels: Res<AlertChannels>,
+        elements: Res<AlertElements>,
+    ) {
+        let mut spawned = HashMap::new();
+        for (alert, channel) in &alerts {
+            let Some(config) = channels.0.get(&channel.0) else {
+                continue;
+            };
+            let existing = roots.iter().find(|(_, root)| root.0 == channel.0);
+            let root = match existing {
+                Some((root, _)) => root,
+                None => *spawned.entry(&channel.0).or_insert_with(|| {
+                    let mut node = elements.root.clone();
+                    config.theme.layout_root(&mut node.style);
+                    let name = Name::new(format!("Alert Channel Root ({})", channel.0));
+                    let root = AlertChannelRoot(channel.0.clone());
+                    commands.spawn((root, name, node)).id()
+                }),
+            };
+            commands.entity(root).add_child(alert);
+        }
+    }
+
+    fn route_overridden_alerts(
+        mut commands: Commands,
+        alerts: Query<Entity, (Added<AlertUi>, Without<AlertChannel>)>,
+        mut default_roots: Query<&mut Style, With<AlertUiRoot>>,
+        nodes: Query<(), With<Node>>,
+        root: Option<Res<AlertRootOverride>>,
+        mut warned: Local<Option<Entity>>,
+    ) {
+        let root = root.map(|root| root.0).filter(|root| {
+            let exists = nodes.contains(*root);
+            if !exists && *warned != Some(*root) {
+                warn!(
+                    target: LOG_TARGET,
+                    "the `AlertRootOverride` node {root} does not exist, so alerts are shown in \
+                    the default root instead"
+                );
+                *warned = Some(*root);
+            }
+            exists
+        });
+        // the default root is still spawned for the alerts, but hidden while they are moved
+        let display = if root.is_some() {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        for mut style in &mut default_roots {
+            if style.display != display {
+                style.display = display;
+            }
+        }
+        if let Some(root) = root {
+            for alert in &alerts {
+                commands.entity(root).add_child(alert);
+            }
+        }
+    }
+
+    fn anchor_alert_root(mut roots: Query<(&mut Style, Ref<AlertUiRoot>)>, theme: Res<AlertTheme>) {
+        for (mut style, root) in &mut roots {
+            if root.is_added() || theme.is_changed() {
+                theme.layout_root(&mut style);
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn build_alert_bodies(
+        mut commands: Commands,
+        alerts: Query<(&AlertMessage, &Children), (With<Alert>, Added<AlertUi>)>,
+        names: Query<&Name>,
+        builder: Option<Res<AlertBuilder>>,
+    ) {
+        let Some(builder) = builder else {
+            return;
+        };
+        for (message, alert_children) in &alerts {
+            for child in alert_children {
+                if names
+                    .get(*child)
+                    .is_ok_and(|name| name.as_str() == "Alert Body UI")
+                {
+                    commands
+                        .entity(*child)
+                        .despawn_descendants()
+                        .with_children(|body| (builder.0)(body, message));
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn style_alerts(
+        mut commands: Commands,
+        mut alerts: Query<
+            (
+                Entity,
+                &Severity,
+                Option<&AlertStyle>,
+                &mut BackgroundColor,
+                &mut Style,
+                &Children,
+                Option<&AlertChannel>,
+            ),
+            (With<Alert>, Added<AlertUi>),
+        >,
+        mut nodes: Query<(&Name, &mut Style, &Children), Without<Alert>>,
+        mut texts: Query<&mut Text>,
+        theme: Res<AlertTheme>,
+        channels: Res<AlertChannels>,
+    ) {
+        for (entity, severity, alert_style, mut background, mut style, alert_children, channel) in
+            &mut alerts
+        {
+            let theme = channels.theme(channel, &theme);
+            let alert_style = alert_style.copied().unwrap_or_default();
+            background.0 = alert_style
+                .background
+                .unwrap_or_else(|| theme.background(*severity));
+            let text_color = alert_style.text_color.unwrap_or(theme.text_color);
+            style.max_width = theme.max_width;
+            commands
+                .entity(entity)
+                .insert(BorderRadius::all(theme.corner_radius));
+            for child in alert_children {
+                let Ok((name, mut style, body_children)) = nodes.get_mut(*child) else {
+                    continue;
+                };
+                if name.as_str() != "Alert Body UI" {
+                    continue;
+                }
+                style.padding = theme.padding;
+                for descendant in body_children {
+                    let Ok(mut text) = texts.get_mut(*descendant) else {
+                        continue;
+                    };
+                    for section in &mut text.sections {
+                        section.style.color = text_color;
+                        section.style.font_size = theme.font_size;
+                        section.style.font.clone_from(&theme.font);
+                    }
+                }
+            }
+        }
+    }
+
+    fn add_severity_icons(
+        mut commands: Commands,
+        alerts: Query<(&Severity, &Children, Option<&AlertChannel>), Added<AlertUi>>,
+        names: Query<&Name>,
+        theme: Res<AlertTheme>,
+        channels: Res<AlertChannels>,
+    ) {
+        for (severity, alert_children, channel) in &alerts {
+            let theme = channels.theme(channel, &theme);
+            let Some(icons) = &theme.icons else {
+                continue;
+            };
+            let body = alert_children.iter().find(|child| {
+                names
+                    .get(**child)
+                    .is_ok_and(|name| name.as_str() == "Alert Body UI")
+            });
+            let Some(body) = body else {
+                continue;
+            };
+            let style = Style {
+                margin: UiRect::right(Val::Px(4.)),
+                ..Default::default()
+            };
+            let mut icon = commands.spawn((Name::new("Alert Icon"), SeverityIcon));
+            match icons.get(*severity) {
+                AlertIcon::Glyph(glyph) => {
+                    let text_style = TextStyle {
+                        font: theme.font.clone(),
+                        font_size: theme.font_size,
+                        color: theme.text_color,
+                    };
+                    icon.insert(
+                        TextBundle::from_section(glyph.clone(), text_style).with_style(style),
+                    );
+                }
+                AlertIcon::Image(image) => {
+                    icon.insert(ImageBundle {
+                        style: Style {
+                            width: Val::Px(theme.font_size),
+                            height: Val::Px(theme.font_size),
+                            ..style
+                        },
+                        image: UiImage::new(image.clone()),
+                        ..Default::default()
+                    });
+                }
+            }
+            let icon = icon.id();
+            commands.entity(*body).insert_children(0, &[icon]);
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn show_alert_texts(
+        alerts: Query<
+            (
+                &AlertMessage,
+                Option<&LocalizedMessage>,
+                Ref<AlertCount>,
+                &RaisedAt,
+                Ref<AlertUi>,
+                &Children,
+            ),
+            With<Alert>,
+        >,
+        children: Query<&Children>,
+        mut texts: Query<(&Name, &mut Text)>,
+        config: Res<AlertConfig>,
+    ) {
+        for (message, localized, count, raised_at, ui, alert_children) in &alerts {
+            if !(count.is_changed() || ui.is_added() || config.is_changed()) {
+                continue;
+            }
+            let shown = localized.map_or(&message.0, |localized| &localized.0);
+            let mut value = config.truncate(shown).into_owned();
+            if config.show_timestamps {
+                value = format!("{} {value}", raised_at.timestamp());
+            }
+            if count.0 > 1 {
+                value = format!("{value} (x{})", count.0);
+            }
+            let descendants = alert_children
+                .iter()
+                .filter_map(|child| children.get(*child).ok())
+                .flat_map(|grandchildren| grandchildren.iter());
+            for descendant in descendants {
+                if let Ok((name, mut text)) = texts.get_mut(*descendant) {
+                    if name.as_str() == "Alert Text" && text.sections[0].value != value {
+                        text.sections[0].value.clone_from(&value);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Plugin for AlertsPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = alert_channel();
+        app.init_resource::<AlertConfig>()
+            .init_resource::<AlertHistory>()
+            .init_resource::<ErrorStats>()
+            .init_resource::<SuppressedAlerts>()
+            .init_resource::<ModalAlertActive>()
+            .insert_resource(AlertChannels(self.channels.iter().cloned().collect()))
+            .insert_resource(
+                self.backend
+                    .clone()
+                    .map_or_else(ActiveAlertBackend::default, ActiveAlertBackend),
+            )
+            .insert_resource(sender)
+            .insert_resource(receiver)
+            .add_event::<AlertDismissed>()
+            .add_event::<AlertRaised>()
+            .add_event::<AlertRepeated>()
+            .observe(ErrorRaised::observe);
+        for register in &self.error_events {
+            register(app.world_mut());
+        }
+        app.add_systems(
+            PostUpdate,
+            summarize_suppressed_alerts.before(raise_sent_alerts),
+        );
+        if !self.reporters.0.is_empty() {
+            app.insert_resource(self.reporters.clone());
+            #[cfg(feature = "ui")]
+            app.observe(AlertReport::on_raised)
+                .observe(AlertReport::on_repeated);
+        }
+        #[cfg(feature = "serde")]
+        if let Some(path) = &self.persisted_history {
+            crate::persist::persist_history(app, path.clone());
+        }
+        #[cfg(feature = "file_log")]
+        if let Some((path, max_size)) = &self.file_log {
+            crate::file_log::log_to_file(app, path.clone(), *max_size);
+        }
+        #[cfg(feature = "ui")]
+        self.build_ui(app);
+        #[cfg(not(feature = "ui"))]
+        app.add_event::<RaisedAlert>()
+            .add_systems(PostUpdate, raise_sent_alerts);
+    }
+}
+
+#[cfg(feature = "ui")]
+impl AlertsPlugin {
+    /// Adds the alert UI and the systems that show, dismiss, and style the alerts.
+    fn build_ui(&self, app: &mut App) {
+        app.init_resource::<LiveAlerts>()
+            .init_resource::<AlertQueue>()
+            .init_resource::<AlertStyleRegistry>()
+            .insert_resource(self.theme.clone().unwrap_or_default())
+            .insert_resource(
+                self.clipboard
+                    .clone()
+                    .map_or_else(ActiveClipboard::default, ActiveClipboard),
+            )
+            .add_event::<ClearAlerts>()
+            // alerts can be despawned by the UI during `PostUpdate` or by users at any time
+            .add_systems(First, Self::forget_despawned_alerts);
+        if let Some(builder) = &self.builder {
+            app.insert_resource(builder.clone());
+        }
+        for schedule in &self.clear_schedules {
+            app.add_systems(*schedule, clear_on_state_change);
+        }
+        if self.headless {
+            app.add_systems(
+                PostUpdate,
+                (
+                    raise_sent_alerts,
+                    Self::clear_alerts,
+                    Self::expire_headless_alerts,
+                    Self::show_queued_alerts,
+                )
+                    .chain(),
+            );
+            return;
+        }
+        app.add_plugins(bevy_ui_mod_alerts::AlertsPlugin::new())
+            // alerts expire according to their `Ttl` and are queued by `AlertQueue` instead
+            .insert_resource(AlertLifetime::<AlertMarker>::new(Duration::MAX))
+            .insert_resource(MaxAlerts::<AlertMarker>::new(usize::MAX))
+            .add_systems(
+                PostUpdate,
+                (
+                    raise_sent_alerts,
+                    Self::clear_alerts,
+                    Self::expire_alerts,
+                    Self::show_queued_alerts,
+                )
+                    .chain()
+                    .before(AlertSystems),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    (
+                        Self::forget_despawned_alerts,
+                        Self::route_channel_alerts,
+                        Self::route_overridden_alerts,
+                        Self::route_modal_alerts,
+                        Self::anchor_alert_root,
+                        Self::build_alert_bodies,
+                        Self::style_alerts,
+                        Self::add_severity_icons,
+                        Self::show_alert_texts,
+                    )
+                        .chain(),
+                    (
+                        Self::remove_dismiss_buttons,
+                        Self::dismiss_pressed_alerts,
+                        Self::make_alerts_clickable,
+                        Self::dismiss_clicked_alerts,
+                        Self::dismiss_alerts_by_hotkey,
+                        Self::add_copy_buttons,
+                        Self::copy_pressed_alerts,
+                        Self::add_action_buttons,
+                        Self::run_pressed_actions,
+                        Self::hide_removed_actions,
+                        Self::add_modal_ok_buttons,
+                        Self::acknowledge_modal_alerts,
+                    )
+                        .chain(),
+                    (
+                        Self::add_ttl_bars,
+                        Self::shrink_ttl_bars,
+                        Self::start_alert_animations,
+                        Self::animate_alerts,
+                        Self::order_alerts,
+                        Self::update_modal_alert_active,
+                    )
+                        .chain(),
+                )
+                    .chain()
+                    .after(AlertSystems),
+            );
+    }
+}
+
+/// The UI node that alerts are added to in place of the alert stack of this crate.
+///
+/// Insert it to show alerts inside a node of your own UI, such as a HUD panel. The alerts are
+/// appended to the children of the node, and are neither positioned by the [`AlertTheme`] nor
+/// ordered by [`AlertConfig::stack`]. Alerts of a channel (see [`AlertsPlugin::with_channel`])
+/// stay in the stack of their channel.
+///
+/// If the node is despawned, alerts are shown in the default stack again and a warning is
+/// logged.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn spawn_hud(mut commands: Commands) {
+///     let panel = commands.spawn(NodeBundle::default()).id();
+///     commands.insert_resource(AlertRootOverride(panel));
+/// }
+/// ```
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Resource)]
+pub struct AlertRootOverride(pub Entity);
+
+/// Settings for the alerts raised by this crate.
+///
+/// Inserted by [`AlertsPlugin`]; changes take effect for subsequently raised alerts.
+#[derive(Debug, Resource)]
+pub struct AlertConfig {
+    /// How long alerts stay shown before they are dismissed, or `None` to keep them until the
+    /// user dismisses them. Defaults to 10 seconds.
+    pub ttl: Option<Duration>,
+    /// The maximum number of alerts shown at once, or `None` for no limit. Defaults to 3.
+    ///
+    /// Alerts raised while the limit is reached wait in the [`AlertQueue`] until there is room
+    /// to show them, unless they replace a shown alert with a lower [`AlertPriority`].
+    #[cfg(feature = "ui")]
+    pub max_visible: Option<usize>,
+    /// When `true`, raising a message that is already shown increments the
+    /// [`AlertCount`](crate::AlertCount) of the existing alert instead of spawning another one.
+    ///
+    /// Alerts are shown in `PostUpdate`, so a message raised on every step of a `FixedUpdate`
+    /// system catching up after a hitch is also shown once, counting every step.
+    pub dedup: bool,
+    /// Alerts less severe than this are not shown, only logged. They are still recorded in the
+    /// [`AlertHistory`] and [`ErrorStats`] and passed to the [`AlertsPlugin::on_alert`]
+    /// callbacks. Defaults to [`Severity::Info`].
+    pub min_severity: Severity,
+    /// When `true`, alert texts are prefixed with the [`RaisedAt::real_time`] of the alert,
+    /// formatted like `"[00:03:45]"`.
+    #[cfg(feature = "ui")]
+    pub show_timestamps: bool,
+    /// When `true`, raised alerts wait in the [`AlertQueue`] instead of being shown, and are
+    /// shown once it is `false` again. Repeated messages are folded while they wait.
+    ///
+    /// Set it while a game state, such as a loading screen, raises errors that aren't worth
+    /// showing yet, for example from systems in `OnEnter` and `OnExit` of that state.
+    #[cfg(feature = "ui")]
+    pub paused: bool,
+    /// When `true`, raised alerts aren't shown at all, for example during cutscenes. They are
+    /// still recorded in the [`AlertHistory`] and passed to the
+    /// [`AlertsPlugin::on_alert`] callbacks, and counted in the [`SuppressedAlerts`].
+    pub muted: bool,
+    /// When `true`, an alert like `"3 alerts suppressed"` is shown once the alerts are unmuted,
+    /// if any were raised while [`AlertConfig::muted`]. Defaults to `true`.
+    pub mute_summary: bool,
+    /// When `true`, alerts have a button that dismisses them. Defaults to `true`.
+    #[cfg(feature = "ui")]
+    pub dismiss_button: bool,
+    /// When `true`, clicking anywhere on an alert dismisses it. Otherwise, clicks go through the
+    /// alerts to the UI below them, except on the dismiss button.
+    #[cfg(feature = "ui")]
+    pub click_to_dismiss: bool,
+    /// The keys that dismiss alerts. Defaults to none.
+    #[cfg(feature = "ui")]
+    pub hotkeys: DismissHotkeys,
+    /// When `true`, alerts have a button that copies their message and timestamp to the
+    /// [`AlertClipboard`]. Defaults to `false`.
+    #[cfg(feature = "ui")]
+    pub copy_button: bool,
+    /// The maximum number of characters of a message shown in an alert, or `None` for no
+    /// limit. Longer messages are cut to end with `"…"`, which counts toward the limit. Defaults
+    /// to 200.
+    ///
+    /// The whole message is kept in the [`AlertMessage`] of the alert and in the
+    /// [`AlertHistory`].
+    #[cfg(feature = "ui")]
+    pub max_message_len: Option<usize>,
+    /// When `true`, alerts with a [`Ttl`] show a bar at their bottom that shrinks as the time
+    /// left runs out. Defaults to `false`.
+    #[cfg(feature = "ui")]
+    pub show_ttl_bar: bool,
+    /// Where new alerts are added to the stack. Defaults to [`StackDirection::NewestOnBottom`].
+    ///
+    /// Stacks anchored to the bottom of the window grow upward either way. Sticky alerts stay
+    /// pinned above the other alerts, then higher [`AlertPriority`] alerts above lower ones.
+    #[cfg(feature = "ui")]
+    pub stack: StackDirection,
+}
+
+/// The order of the alerts in the stack, see [`AlertConfig::stack`].
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StackDirection {
+    /// The most recently raised alert is shown above the others.
+    NewestOnTop,
+    /// The most recently raised alert is shown below the others.
+    #[default]
+    NewestOnBottom,
+}
+
+/// The keys that dismiss alerts, see [`AlertConfig::hotkeys`].
+///
+/// The keys are read from `ButtonInput<KeyCode>` without clearing them, so other systems still
+/// see them pressed.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// let hotkeys = DismissHotkeys {
+///     dismiss_latest: Some(Hotkey::new(KeyCode::Escape)),
+///     dismiss_all: Some(Hotkey::new(KeyCode::Escape).with_shift()),
+/// };
+/// ```
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DismissHotkeys {
+    /// Dismisses the most recently raised alert.
+    pub dismiss_latest: Option<Hotkey>,
+    /// Dismisses every shown alert.
+    pub dismiss_all: Option<Hotkey>,
+}
+
+/// A key, optionally pressed while holding shift.
+#[cfg(feature = "ui")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Hotkey {
+    /// The key to press.
+    pub key: KeyCode,
+    /// Whether either shift key must be held. When `false`, shift must not be held.
+    pub shift: bool,
+}
+
+#[cfg(feature = "ui")]
+impl Hotkey {
+    /// Builds a hotkey pressing `key` without shift.
+    pub fn new(key: KeyCode) -> Self {
+        Self { key, shift: false }
+    }
+
+    /// Requires shift to be held.
+    pub fn with_shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Whether the hotkey was just pressed.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        keys.just_pressed(self.key) && shift == self.shift
+    }
+}
+
+#[cfg(feature = "ui")]
+impl AlertConfig {
+    /// Shortens `message` to [`AlertConfig::max_message_len`] characters, including the `"…"`.
+    pub(crate) fn truncate<'a>(&self, message: &'a str) -> Cow<'a, str> {
+        let Some(max_len) = self.max_message_len else {
+            return Cow::Borrowed(message);
+        };
+        if message.chars().nth(max_len).is_none() {
+            return Cow::Borrowed(message);
+        }
+        match message.char_indices().nth(max_len.saturating_sub(1)) {
+            Some((end, _)) if max_len > 0 => Cow::Owned(format!("{}…", &message[..end])),
+            _ => Cow::Borrowed(""),
+        }
+    }
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Some(Duration::from_secs(10)),
+            #[cfg(feature = "ui")]
+            max_visible: Some(3),
+            dedup: true,
+            min_severity: Severity::Info,
+            #[cfg(feature = "ui")]
+            show_timestamps: false,
+            #[cfg(feature = "ui")]
+            paused: false,
+            muted: false,
+            mute_summary: true,
+            #[cfg(feature = "ui")]
+            dismiss_button: true,
+            #[cfg(feature = "ui")]
+            click_to_dismiss: false,
+            #[cfg(feature = "ui")]
+            hotkeys: DismissHotkeys::default(),
+            #[cfg(feature = "ui")]
+            copy_button: false,
+            #[cfg(feature = "ui")]
+            max_message_len: Some(200),
+            #[cfg(feature = "ui")]
+            show_ttl_bar: false,
+            #[cfg(feature = "ui")]
+            stack: StackDirection::default(),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::VecDeque;
+
+use bevy_ecs::prelude::*;
+use bevy_ui_mod_alerts::{Alert, AlertMarker};
+
+use crate::{
+    AlertActions, AlertAge, AlertChannel, AlertConfig, AlertCount, AlertKey, AlertLocalizer,
+    AlertMessage, AlertPriority, AlertRaised, AlertRepeated, AlertSource, LiveAlerts,
+    LocalizedMessage, ModalAlert, RaisedAlert, RaisedAt, Sticky, Ttl,
+};
+
+/// The alerts waiting to be shown, oldest first.
+///
+/// Alerts raised by this crate wait here until fewer than [`AlertConfig::max_visible`] alerts
+/// are shown, so the queue is usually drained on the frame the alerts were raised.
+///
+/// Alerts are queued with the highest [`AlertPriority`] first (by default the most severe), then
+/// oldest first. Alerts raised on the same frame are shown together at the end of the frame, so
+/// they are stacked in the same order on every run.
+///
+/// [`AlertConfig::max_visible`]: crate::AlertConfig::max_visible
+#[derive(Debug, Default, Resource)]
+pub struct AlertQueue {
+    pub(crate) alerts: VecDeque<RaisedAlert>,
+    /// The number of alerts shown from the queue so far.
+    pub(crate) shown: u64,
+}
+
+/// The order in which alerts were shown, used to sort the alert stack.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Component)]
+pub(crate) struct ShownOrder(pub(crate) u64);
+
+impl AlertQueue {
+    /// Iterates over the queued alerts, oldest first.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &RaisedAlert> + '_ {
+        self.alerts.iter()
+    }
+
+    /// The number of queued alerts.
+    pub fn len(&self) -> usize {
+        self.alerts.len()
+    }
+
+    /// Whether no alerts are queued.
+    pub fn is_empty(&self) -> bool {
+        self.alerts.is_empty()
+    }
+
+    /// Drops every queued alert.
+    pub fn clear(&mut self) {
+        self.alerts.clear();
+    }
+
+    /// Queues `alerts` in `world`, folding any alert that is already shown or queued into the
+    /// existing one if [`AlertConfig::dedup`] is enabled.
+    ///
+    /// [`AlertConfig::dedup`]: crate::AlertConfig::dedup
+    pub(crate) fn push(world: &mut World, alerts: Vec<RaisedAlert>) {
+        let dedup = world
+            .get_resource::<AlertConfig>()
+            .map_or(true, |config| config.dedup);
+        for alert in alerts {
+            if dedup && Self::fold(world, &alert) {
+                continue;
+            }
+            world.resource_mut::<AlertQueue>().insert(alert);
+        }
+    }
+
+    /// Queues `alert` after the alerts with at least its priority.
+    fn insert(&mut self, alert: RaisedAlert) {
+        let at = self
+            .alerts
+            .iter()
+            .rposition(|queued| queued.priority >= alert.priority)
+            .map_or(0, |before| before + 1);
+        self.alerts.insert(at, alert);
+    }
+
+    /// Folds `alert` into the shown or queued alert with the same message and severity, returning
+    /// whether there was one.
+    fn fold(world: &mut World, alert: &RaisedAlert) -> bool {
+        let key = alert.key();
+        if let Some((entity, count)) = world.resource_mut::<LiveAlerts>().alerts.get_mut(&key) {
+            *count += alert.count;
+            let (entity, count) = (*entity, *count);
+            if let Some(mut alert_entity) = world.get_entity_mut(entity) {
+                alert_entity.insert(AlertCount(count));
+                if let Some(mut raised_at) = alert_entity.get_mut::<RaisedAt>() {
+                    raised_at.last_seen = alert.raised_at.last_seen;
+                }
+                let repeated = AlertRepeated { entity, count };
+                world.send_event(repeated);
+                world.trigger_targets(repeated, entity);
+            }
+            return true;
+        }
+        let mut queue = world.resource_mut::<AlertQueue>();
+        let Some(queued) = queue.find_mut(alert) else {
+            return false;
+        };
+        queued.count += alert.count;
+        queued.raised_at.last_seen = alert.raised_at.last_seen;
+        true
+    }
+
+    /// The queued alert with the message, severity, and channel of `alert`, if any.
+    pub(crate) fn find_mut(&mut self, alert: &RaisedAlert) -> Option<&mut RaisedAlert> {
+        self.alerts.iter_mut().find(|queued| {
+            queued.severity == alert.severity
+                && queued.message == alert.message
+                && queued.channel == alert.channel
+        })
+    }
+}
+
+impl RaisedAlert {
+    /// The key of the alert in [`LiveAlerts`].
+    pub(crate) fn key(&self) -> AlertKey {
+        (self.severity, self.message.clone(), self.channel.clone())
+    }
+
+    /// Spawns the alert entity for this alert, translated by `localizer`.
+    pub(crate) fn spawn(
+        self,
+        commands: &mut Commands,
+        order: ShownOrder,
+        localizer: Option<&AlertLocalizer>,
+    ) -> Entity {
+        let message = self.message.to_string();
+        let localized =
+            localizer.and_then(|localizer| localizer.localize(&self.message, self.l10n.as_ref()));
+        let mut alert = commands.spawn((
+            Alert::bundle(localized.clone().unwrap_or_else(|| message.clone())),
+            AlertMarker,
+            AlertMessage(message.clone()),
+            self.severity,
+            AlertCount(self.count),
+            self.raised_at,
+            AlertPriority(self.priority),
+            order,
+        ));
+        if let Some(ttl) = self.ttl {
+            alert.insert((Ttl(ttl), AlertAge::default()));
+        }
+        if self.sticky {
+            alert.insert(Sticky);
+        }
+        if let Some(source) = self.source {
+            alert.insert(AlertSource(source));
+        }
+        if let Some(style) = self.style {
+            alert.insert(style);
+        }
+        if let Some(channel) = self.channel {
+            alert.insert(AlertChannel(channel));
+        }
+        for tag in &self.tags {
+            tag.insert(&mut alert);
+        }
+        if let Some(payload) = &self.payload {
+            payload.insert(&mut alert);
+        }
+        if let Some(meta) = self.meta {
+            alert.insert(meta);
+        }
+        if !self.actions.is_empty() {
+            alert.insert(AlertActions(self.actions));
+        }
+        if self.modal {
+            alert.insert(ModalAlert);
+        }
+        if let Some(localized) = localized {
+            alert.insert(LocalizedMessage(localized));
+        }
+        #[cfg(feature = "backtrace")]
+        alert.insert(self.backtrace);
+        let entity = alert.id();
+        let raised = AlertRaised {
+            entity,
+            message,
+            severity: self.severity,
+        };
+        commands.add(move |world: &mut World| {
+            world.send_event(raised.clone());
+            world.trigger_targets(raised, entity);
+        });
+        entity
+    }
+}
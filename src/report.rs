@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_tasks::AsyncComputeTaskPool;
+
+use crate::{RaisedAlert, RaisedAt, Severity};
+
+/// An alert passed to the callbacks of [`AlertsPlugin::on_alert`](crate::AlertsPlugin::on_alert).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlertReport {
+    /// Whether the alert was raised or a repeated message was folded into it.
+    pub kind: AlertReportKind,
+    /// The message of the alert.
+    pub message: String,
+    /// The severity of the alert.
+    pub severity: Severity,
+    /// The support code of the alert, see [`AlertMeta`](crate::AlertMeta).
+    pub code: Option<String>,
+    /// The messages of the error and its causes, outermost first, or just the message if the
+    /// alert wasn't raised for an `anyhow::Error`.
+    pub chain: Vec<String>,
+    /// When the alert was first and last raised.
+    pub raised_at: RaisedAt,
+    /// The number of times the message was raised, see [`AlertCount`](crate::AlertCount).
+    pub count: usize,
+}
+
+/// What an [`AlertReport`] is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AlertReportKind {
+    /// The alert was raised, like [`AlertRaised`](crate::AlertRaised).
+    Raised,
+    /// A repeated message was folded into the alert by
+    /// [`AlertConfig::dedup`](crate::AlertConfig::dedup), like
+    /// [`AlertRepeated`](crate::AlertRepeated).
+    Repeated,
+}
+
+type ReportCallback = dyn Fn(&AlertReport) + Send + Sync;
+
+/// The callbacks registered with [`AlertsPlugin::on_alert`](crate::AlertsPlugin::on_alert).
+#[derive(Clone, Default, Resource)]
+pub(crate) struct AlertReporters(pub(crate) Vec<(Arc<ReportCallback>, bool)>);
+
+impl AlertReporters {
+    pub(crate) fn add(
+        &mut self,
+        callback: impl Fn(&AlertReport) + Send + Sync + 'static,
+        in_background: bool,
+    ) {
+        self.0.push((Arc::new(callback), in_background));
+    }
+
+    /// Calls every callback with `report`, spawning the background ones on the
+    /// [`AsyncComputeTaskPool`].
+    pub(crate) fn report(&self, report: AlertReport) {
+        for (callback, in_background) in &self.0 {
+            if *in_background {
+                let (callback, report) = (callback.clone(), report.clone());
+                AsyncComputeTaskPool::get()
+                    .spawn(async move { callback(&report) })
+                    .detach();
+            } else {
+                callback(&report);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for AlertReporters {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AlertReporters")
+            .field(&self.0.len())
+            .finish()
+    }
+}
+
+impl AlertReport {
+    /// The report of `alert` being raised.
+    pub(crate) fn raised(alert: &RaisedAlert) -> Self {
+        Self {
+            kind: AlertReportKind::Raised,
+            message: alert.message.to_string(),
+            severity: alert.severity,
+            code: alert.meta.as_ref().and_then(|meta| meta.code.clone()),
+            chain: vec![alert.message.to_string()],
+            raised_at: alert.raised_at,
+            count: alert.count,
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+type ReportedAlert<'a> = (
+    &'a crate::AlertMessage,
+    &'a Severity,
+    &'a RaisedAt,
+    &'a crate::AlertCount,
+    Option<&'a crate::AlertMeta>,
+    Option<&'a crate::ErrorPayload<anyhow::Error>>,
+);
+
+#[cfg(feature = "ui")]
+impl AlertReport {
+    /// The report of the spawned alert `alert`.
+    fn of(kind: AlertReportKind, alert: ReportedAlert) -> Self {
+        let (message, severity, raised_at, count, meta, payload) = alert;
+        let chain = match payload {
+            Some(crate::ErrorPayload(error)) => error.chain().map(ToString::to_string).collect(),
+            None => vec![message.0.clone()],
+        };
+        Self {
+            kind,
+            message: message.0.clone(),
+            severity: *severity,
+            code: meta.and_then(|meta| meta.code.clone()),
+            chain,
+            raised_at: *raised_at,
+            count: count.0,
+        }
+    }
+
+    /// Reports the alerts spawned by the UI.
+    pub(crate) fn on_raised(
+        trigger: Trigger<crate::AlertRaised>,
+        alerts: Query<ReportedAlert>,
+        reporters: Res<AlertReporters>,
+    ) {
+        if let Ok(alert) = alerts.get(trigger.entity()) {
+            reporters.report(Self::of(AlertReportKind::Raised, alert));
+        }
+    }
+
+    /// Reports the messages folded into shown alerts.
+    pub(crate) fn on_repeated(
+        trigger: Trigger<crate::AlertRepeated>,
+        alerts: Query<ReportedAlert>,
+        reporters: Res<AlertReporters>,
+    ) {
+        if let Ok(alert) = alerts.get(trigger.entity()) {
+            reporters.report(Self::of(AlertReportKind::Repeated, alert));
+        }
+    }
+}
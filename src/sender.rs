@@ -0,0 +1,88 @@
+use std::sync::{
+    mpsc::{channel, Receiver, Sender},
+    Mutex,
+};
+
+use bevy_ecs::prelude::*;
+
+use crate::{AlertMessage, Alerts, Severity};
+
+/// A thread-safe handle for raising alerts from outside of systems.
+///
+/// Inserted by [`AlertsPlugin`](crate::AlertsPlugin). Clone it into threads, tasks, or error
+/// callbacks that don't have access to the [`World`]; the alerts are raised through [`Alerts`]
+/// at the start of the next `PostUpdate`.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn start_download(sender: Res<AlertSender>) {
+///     let sender = sender.clone();
+///     std::thread::spawn(move || sender.send("download failed"));
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Startup, start_download);
+/// ```
+#[derive(Clone, Debug, Resource)]
+pub struct AlertSender(Sender<(String, Severity)>);
+
+impl AlertSender {
+    /// Raises an alert showing `message`.
+    pub fn send(&self, message: impl std::fmt::Display) {
+        self.send_with_severity(message, Severity::Error);
+    }
+
+    /// Raises an alert with the given [`Severity`] showing `message`.
+    pub fn send_with_severity(&self, message: impl std::fmt::Display, severity: Severity) {
+        self.send_message(AlertMessage(message.to_string()), severity);
+    }
+
+    /// Raises an alert with the given [`Severity`] showing a prebuilt `message`.
+    pub fn send_message(&self, message: AlertMessage, severity: Severity) {
+        // the receiver lives as long as the app, and alerts sent after that have nowhere to go
+        let _ = self.0.send((message.0, severity));
+    }
+
+    /// A handle sending alerts through the same channel, which can be cloned and sent across
+    /// threads.
+    pub fn handle(&self) -> AlertSender {
+        self.clone()
+    }
+}
+
+/// The [`AlertSender`] under the name used for reporting errors from outside of the ECS, such as
+/// from asset processing threads or async tasks.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn process_assets(sink: Res<ErrorSink>) {
+///     let handle = sink.handle();
+///     std::thread::spawn(move || {
+///         handle.send_message(AlertMessage("texture is too large".into()), Severity::Warning);
+///     });
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Startup, process_assets);
+/// ```
+pub type ErrorSink = AlertSender;
+
+/// The receiving end of the [`AlertSender`].
+#[derive(Debug, Resource)]
+pub(crate) struct AlertReceiver(Mutex<Receiver<(String, Severity)>>);
+
+/// Builds a connected [`AlertSender`] and [`AlertReceiver`].
+pub(crate) fn alert_channel() -> (AlertSender, AlertReceiver) {
+    let (sender, receiver) = channel();
+    (AlertSender(sender), AlertReceiver(Mutex::new(receiver)))
+}
+
+/// Raises the alerts sent through the [`AlertSender`].
+pub(crate) fn raise_sent_alerts(receiver: Res<AlertReceiver>, mut alerts: Alerts) {
+    let receiver = receiver.0.lock().unwrap_or_else(|error| error.into_inner());
+    for (message, severity) in receiver.try_iter() {
+        alerts.push_with_severity(message, severity);
+    }
+}
@@ -0,0 +1,76 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy_ecs::prelude::*;
+
+/// Occurrence counters for every alert message raised by this crate.
+///
+/// Inserted by [`AlertsPlugin`](crate::AlertsPlugin). Unlike [`AlertHistory`](crate::AlertHistory),
+/// this is never trimmed; call [`ErrorStats::reset`] to start over.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct ErrorStats {
+    stats: HashMap<String, ErrorStat>,
+    total: usize,
+}
+
+/// How often an alert message was raised, tracked by [`ErrorStats`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorStat {
+    /// The number of times the message was raised.
+    pub count: usize,
+    /// The [`Time<Real>`](bevy_time::Real) elapsed when the message was last raised.
+    pub last_seen: Duration,
+}
+
+impl ErrorStats {
+    /// The stats of `message`, if it was ever raised.
+    pub fn get(&self, message: &str) -> Option<&ErrorStat> {
+        self.stats.get(message)
+    }
+
+    /// Iterates over the stats of every raised message, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ErrorStat)> + '_ {
+        self.stats
+            .iter()
+            .map(|(message, stat)| (message.as_str(), stat))
+    }
+
+    /// The `n` most frequently raised messages, most frequent first.
+    ///
+    /// Messages raised equally often are ordered alphabetically.
+    pub fn top(&self, n: usize) -> Vec<(&str, &ErrorStat)> {
+        let mut stats = self.iter().collect::<Vec<_>>();
+        stats.sort_by(|(a, a_stat), (b, b_stat)| b_stat.count.cmp(&a_stat.count).then(a.cmp(b)));
+        stats.truncate(n);
+        stats
+    }
+
+    /// The number of messages raised, counting repeated ones.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Removes the stats of every message.
+    pub fn reset(&mut self) {
+        self.stats.clear();
+        self.total = 0;
+    }
+
+    pub(crate) fn record(&mut self, message: &str, now: Duration) {
+        self.total += 1;
+        match self.stats.get_mut(message) {
+            Some(stat) => {
+                stat.count += 1;
+                stat.last_seen = now;
+            }
+            None => {
+                self.stats.insert(
+                    message.to_string(),
+                    ErrorStat {
+                        count: 1,
+                        last_seen: now,
+                    },
+                );
+            }
+        }
+    }
+}
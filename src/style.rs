@@ -0,0 +1,83 @@
+use std::any::{Any, TypeId};
+
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+
+/// Overrides the `AlertTheme` for a single alert.
+///
+/// Inserted on alert entities whose error type is registered in the [`AlertStyleRegistry`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Component)]
+pub struct AlertStyle {
+    /// The background color of the alert, instead of the severity's theme color.
+    pub background: Option<Color>,
+    /// The color of the alert text, instead of the theme's text color.
+    pub text_color: Option<Color>,
+}
+
+/// The [`AlertStyle`] of each registered error type.
+///
+/// Inserted by [`AlertsPlugin`](crate::AlertsPlugin). The alert pipes for single errors, like
+/// [`AnyhowAlertExt::anyhow_alert`](crate::AnyhowAlertExt::anyhow_alert), look up the style of
+/// the error they raise an alert for. Styles registered for a type also apply to an
+/// [`anyhow::Error`] wrapping that type.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+/// use bevy_color::palettes::css;
+///
+/// #[derive(Debug, thiserror::Error)]
+/// #[error("connection lost")]
+/// struct NetworkError;
+///
+/// fn style_network_errors(mut styles: ResMut<AlertStyleRegistry>) {
+///     styles.register::<NetworkError>(AlertStyle {
+///         background: Some(css::ORANGE.into()),
+///         ..Default::default()
+///     });
+/// }
+/// # let mut app = App::new();
+/// # app.add_systems(Startup, style_network_errors);
+/// ```
+#[derive(Debug, Default, Resource)]
+pub struct AlertStyleRegistry {
+    styles: Vec<RegisteredStyle>,
+}
+
+#[derive(Debug)]
+struct RegisteredStyle {
+    type_id: TypeId,
+    wraps: fn(&anyhow::Error) -> bool,
+    style: AlertStyle,
+}
+
+impl AlertStyleRegistry {
+    /// Styles the alerts raised for errors of type `E`, replacing any style registered before.
+    pub fn register<E>(&mut self, style: AlertStyle) -> &mut Self
+    where
+        E: std::fmt::Display + std::fmt::Debug + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<E>();
+        self.styles
+            .retain(|registered| registered.type_id != type_id);
+        self.styles.push(RegisteredStyle {
+            type_id,
+            wraps: |error| error.is::<E>(),
+            style,
+        });
+        self
+    }
+
+    /// The style registered for the type of `error`, or for the type it wraps if it is an
+    /// [`anyhow::Error`].
+    pub fn get<Err: 'static>(&self, error: &Err) -> Option<AlertStyle> {
+        let type_id = TypeId::of::<Err>();
+        let wrapped = (error as &dyn Any).downcast_ref::<anyhow::Error>();
+        self.styles
+            .iter()
+            .find(|registered| {
+                registered.type_id == type_id || wrapped.is_some_and(registered.wraps)
+            })
+            .map(|registered| registered.style)
+    }
+}
@@ -0,0 +1,94 @@
+use std::marker::PhantomData;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_tasks::{block_on, poll_once, Task};
+
+use crate::{Alerts, Severity};
+
+/// A component holding a [`Task`] that can fail, polled by [`TaskAlertPlugin`].
+///
+/// ```
+/// use bevy::{prelude::*, tasks::Task};
+/// use bevy_anyhow_alert::*;
+///
+/// #[derive(Component)]
+/// struct LoadLevel(Task<anyhow::Result<String>>);
+///
+/// impl FallibleTask for LoadLevel {
+///     type Output = String;
+///     type Error = anyhow::Error;
+///
+///     fn task_mut(&mut self) -> &mut Task<anyhow::Result<String>> {
+///         &mut self.0
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(TaskAlertPlugin::<LoadLevel>::default());
+/// ```
+pub trait FallibleTask: Component {
+    /// The value of the task when it succeeds, sent as a [`TaskCompleted`] event.
+    type Output: Send + Sync + 'static;
+    /// The error of the task when it fails, shown in an alert.
+    type Error: std::fmt::Display;
+
+    /// The task held by this component.
+    fn task_mut(&mut self) -> &mut Task<Result<Self::Output, Self::Error>>;
+}
+
+/// Sent when the task of a [`FallibleTask`] component succeeds.
+#[derive(Debug, Event)]
+pub struct TaskCompleted<C: FallibleTask> {
+    /// The entity the task component was removed from.
+    pub entity: Entity,
+    /// The value of the task.
+    pub output: C::Output,
+}
+
+/// Polls the tasks of `C` components each `PreUpdate`, see [`poll_task_alerts`].
+///
+/// Requires the [`AlertsPlugin`](crate::AlertsPlugin).
+pub struct TaskAlertPlugin<C>(PhantomData<C>);
+
+impl<C> Default for TaskAlertPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: FallibleTask> Plugin for TaskAlertPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TaskCompleted<C>>()
+            .add_systems(PreUpdate, poll_task_alerts::<C>);
+    }
+}
+
+/// Removes the `C` components whose task has finished, raising an alert for the entity if the
+/// task failed and sending a [`TaskCompleted`] otherwise.
+///
+/// A task is cancelled when its component is removed or its entity is despawned before it
+/// finishes; no alert is raised for it.
+pub fn poll_task_alerts<C: FallibleTask>(
+    mut commands: Commands,
+    mut tasks: Query<(Entity, &mut C)>,
+    mut completed: EventWriter<TaskCompleted<C>>,
+    mut alerts: Alerts,
+) {
+    for (entity, mut task) in &mut tasks {
+        let task = task.task_mut();
+        if !task.is_finished() {
+            continue;
+        }
+        let Some(result) = block_on(poll_once(task)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<C>();
+        match result {
+            Ok(output) => {
+                completed.send(TaskCompleted { entity, output });
+            }
+            Err(error) => alerts.push_from(error, Severity::Error, entity),
+        }
+    }
+}
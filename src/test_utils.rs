@@ -0,0 +1,109 @@
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+
+use crate::AlertHistory;
+
+/// Assertions on the alerts raised in an [`App`] or [`World`], for headless tests.
+///
+/// The alerts are read from the [`AlertHistory`], so they are counted whichever
+/// [`AlertBackend`](crate::AlertBackend) is installed, including the ones waiting in the queue,
+/// already dismissed, or folded into a shown alert, and the ones not shown because of
+/// [`AlertConfig::min_severity`](crate::AlertConfig::min_severity) or
+/// [`AlertConfig::muted`](crate::AlertConfig::muted).
+///
+/// Requires the `test_utils` feature, and panics if [`AlertsPlugin`](crate::AlertsPlugin) wasn't
+/// added.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_anyhow_alert::*;
+///
+/// fn load_save() -> anyhow::Result<()> {
+///     anyhow::bail!("save file is corrupt")
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AlertsPlugin::new()));
+/// app.add_systems(Update, load_save.anyhow_alert());
+/// app.assert_no_alerts();
+/// app.update();
+/// app.assert_alerted("corrupt");
+/// assert_eq!(app.alert_count(), 1);
+/// ```
+pub trait AlertTestExt {
+    /// The number of alerts raised since the app started or [`AlertTestExt::clear_alerts`].
+    fn alert_count(&self) -> usize;
+
+    /// The messages of the alerts raised, oldest first, with repeated messages listed once in
+    /// a row.
+    fn alert_messages(&self) -> Vec<String>;
+
+    /// Panics unless the message of a raised alert contains `substring`.
+    #[track_caller]
+    fn assert_alerted(&self, substring: &str) {
+        let messages = self.alert_messages();
+        assert!(
+            messages.iter().any(|message| message.contains(substring)),
+            "expected an alert containing {substring:?}, but the alerts were {messages:?}"
+        );
+    }
+
+    /// Panics if any alert was raised.
+    #[track_caller]
+    fn assert_no_alerts(&self) {
+        let messages = self.alert_messages();
+        assert!(
+            messages.is_empty(),
+            "expected no alerts, but the alerts were {messages:?}"
+        );
+    }
+
+    /// Forgets the raised alerts, and dismisses the shown and queued ones.
+    fn clear_alerts(&mut self);
+}
+
+fn history(world: &World) -> &AlertHistory {
+    world
+        .get_resource::<AlertHistory>()
+        .expect("`AlertsPlugin` must be added to the app to test its alerts")
+}
+
+impl AlertTestExt for World {
+    fn alert_count(&self) -> usize {
+        history(self)
+            .iter()
+            .filter(|entry| !entry.previous_session)
+            .map(|entry| entry.count)
+            .sum()
+    }
+
+    fn alert_messages(&self) -> Vec<String> {
+        history(self)
+            .iter()
+            .filter(|entry| !entry.previous_session)
+            .map(|entry| entry.message.clone())
+            .collect()
+    }
+
+    fn clear_alerts(&mut self) {
+        #[cfg(feature = "ui")]
+        bevy_ecs::world::Command::apply(crate::ClearAlerts, self);
+        if let Some(mut history) = self.get_resource_mut::<AlertHistory>() {
+            history.clear();
+        }
+    }
+}
+
+impl AlertTestExt for App {
+    fn alert_count(&self) -> usize {
+        self.world().alert_count()
+    }
+
+    fn alert_messages(&self) -> Vec<String> {
+        self.world().alert_messages()
+    }
+
+    fn clear_alerts(&mut self) {
+        self.world_mut().clear_alerts();
+    }
+}
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use bevy_asset::Handle;
+use bevy_color::{palettes::css, Color};
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::ChildBuilder;
+use bevy_math::Vec2;
+use bevy_render::texture::Image;
+use bevy_text::Font;
+use bevy_ui::{AlignItems, JustifyContent, Style, UiRect, Val};
+
+use crate::{AlertAnimation, AlertMessage, Severity};
+
+/// The look of the alerts shown by this crate.
+///
+/// Inserted by [`AlertsPlugin`](crate::AlertsPlugin), see
+/// [`AlertsPlugin::with_theme`](crate::AlertsPlugin::with_theme). Changes apply to
+/// subsequently shown alerts. The defaults match the `bevy_ui_mod_alerts` toasts.
+///
+/// ```
+/// use bevy::{color::palettes::css, prelude::*};
+/// use bevy_anyhow_alert::*;
+///
+/// let theme = AlertTheme {
+///     error_background: css::MISTY_ROSE.into(),
+///     font_size: 18.,
+///     ..Default::default()
+/// };
+/// let mut app = App::new();
+/// app.add_plugins(AlertsPlugin::new().with_theme(theme));
+/// ```
+#[derive(Clone, Debug, Resource)]
+pub struct AlertTheme {
+    /// The background color of [`Severity::Info`] alerts.
+    pub info_background: Color,
+    /// The background color of [`Severity::Warning`] alerts.
+    pub warning_background: Color,
+    /// The background color of [`Severity::Error`] alerts.
+    pub error_background: Color,
+    /// The color of the alert text.
+    pub text_color: Color,
+    /// The font of the alert text.
+    pub font: Handle<Font>,
+    /// The font size of the alert text.
+    pub font_size: f32,
+    /// The space between the edges of an alert and its text.
+    pub padding: UiRect,
+    /// The radius of the corners of an alert.
+    pub corner_radius: Val,
+    /// The maximum width of an alert.
+    pub max_width: Val,
+    /// The corner or edge of the window the alerts are stacked in. Defaults to
+    /// [`AlertAnchor::BottomRight`].
+    pub anchor: AlertAnchor,
+    /// The distance in pixels between the alerts and the `anchor`, horizontally and vertically.
+    ///
+    /// Centered anchors are shifted right by `offset.x`, or left if it is negative.
+    pub offset: Vec2,
+    /// How alerts appear and disappear.
+    pub animation: AlertAnimation,
+    /// The icons shown before the text of alerts, or `None` to show no icons.
+    pub icons: Option<AlertIcons>,
+}
+
+/// The icon of each [`Severity`], see [`AlertTheme::icons`].
+///
+/// The default icons are the `ℹ`, `⚠` and `✖` glyphs.
+#[derive(Clone, Debug)]
+pub struct AlertIcons {
+    /// The icon of [`Severity::Info`] alerts.
+    pub info: AlertIcon,
+    /// The icon of [`Severity::Warning`] alerts.
+    pub warning: AlertIcon,
+    /// The icon of [`Severity::Error`] alerts.
+    pub error: AlertIcon,
+}
+
+impl AlertIcons {
+    /// The icon of alerts with `severity`.
+    pub fn get(&self, severity: Severity) -> &AlertIcon {
+        match severity {
+            Severity::Info => &self.info,
+            Severity::Warning => &self.warning,
+            Severity::Error => &self.error,
+        }
+    }
+}
+
+impl Default for AlertIcons {
+    fn default() -> Self {
+        Self {
+            info: AlertIcon::Glyph("ℹ".to_string()),
+            warning: AlertIcon::Glyph("⚠".to_string()),
+            error: AlertIcon::Glyph("✖".to_string()),
+        }
+    }
+}
+
+/// An icon shown on alerts, see [`AlertIcons`].
+#[derive(Clone, Debug)]
+pub enum AlertIcon {
+    /// Text in the font of the alert text.
+    Glyph(String),
+    /// An image, shown as big as the alert text.
+    Image(Handle<Image>),
+}
+
+/// Marks the icon node of an alert, see [`AlertTheme::icons`].
+#[derive(Clone, Copy, Debug, Default, Component)]
+pub struct SeverityIcon;
+
+/// Where the alerts are stacked, see [`AlertTheme::anchor`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AlertAnchor {
+    /// The top left corner.
+    TopLeft,
+    /// The middle of the top edge.
+    TopCenter,
+    /// The top right corner.
+    TopRight,
+    /// The bottom left corner.
+    BottomLeft,
+    /// The middle of the bottom edge.
+    BottomCenter,
+    /// The bottom right corner.
+    #[default]
+    BottomRight,
+}
+
+impl AlertTheme {
+    /// The background color of alerts with `severity`.
+    pub fn background(&self, severity: Severity) -> Color {
+        match severity {
+            Severity::Info => self.info_background,
+            Severity::Warning => self.warning_background,
+            Severity::Error => self.error_background,
+        }
+    }
+
+    /// The width of the alert stack, as a percentage of the window.
+    const STACK_WIDTH: f32 = 30.;
+
+    /// Positions the root node of the alert stack according to `anchor` and `offset`.
+    pub(crate) fn layout_root(&self, style: &mut Style) {
+        use AlertAnchor::*;
+        let (x, y) = (Val::Px(self.offset.x), Val::Px(self.offset.y));
+        style.width = Val::Percent(Self::STACK_WIDTH);
+        (style.left, style.right, style.align_items) = match self.anchor {
+            TopLeft | BottomLeft => (x, Val::Auto, AlignItems::FlexStart),
+            TopCenter | BottomCenter => (
+                Val::Percent((100. - Self::STACK_WIDTH) / 2.),
+                Val::Auto,
+                AlignItems::Center,
+            ),
+            TopRight | BottomRight => (Val::Auto, x, AlignItems::FlexEnd),
+        };
+        style.margin.left = match self.anchor {
+            TopCenter | BottomCenter => x,
+            _ => Val::ZERO,
+        };
+        (style.top, style.bottom, style.justify_content) = match self.anchor {
+            TopLeft | TopCenter | TopRight => (y, Val::Auto, JustifyContent::FlexStart),
+            BottomLeft | BottomCenter | BottomRight => (Val::Auto, y, JustifyContent::FlexEnd),
+        };
+    }
+}
+
+impl Default for AlertTheme {
+    fn default() -> Self {
+        Self {
+            info_background: css::ALICE_BLUE.into(),
+            warning_background: css::ALICE_BLUE.into(),
+            error_background: css::ALICE_BLUE.into(),
+            text_color: Color::BLACK,
+            font: Handle::default(),
+            font_size: 24.,
+            padding: UiRect::all(Val::Px(4.)),
+            corner_radius: Val::ZERO,
+            max_width: Val::Auto,
+            anchor: AlertAnchor::default(),
+            offset: Vec2::splat(24.),
+            animation: AlertAnimation::default(),
+            icons: None,
+        }
+    }
+}
+
+type BuildAlert = dyn Fn(&mut ChildBuilder, &AlertMessage) + Send + Sync;
+
+/// Builds the body of every alert in place of its default text, see
+/// [`AlertsPlugin::with_builder`](crate::AlertsPlugin::with_builder).
+#[derive(Clone, Resource)]
+pub(crate) struct AlertBuilder(pub(crate) Arc<BuildAlert>);
+
+impl std::fmt::Debug for AlertBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AlertBuilder").finish_non_exhaustive()
+    }
+}
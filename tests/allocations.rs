@@ -0,0 +1,118 @@
+//! Counts the allocations of raising alerts, in its own test binary so that the counting
+//! allocator doesn't slow down the other tests.
+
+// the captured backtrace of each alert is allocated
+#![cfg(not(feature = "backtrace"))]
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use bevy::{ecs::schedule::ExecutorKind, prelude::*};
+use bevy_anyhow_alert::*;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+// SAFETY: forwards to the system allocator, only counting the allocations of each thread
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+struct DropAlerts;
+
+impl AlertBackend for DropAlerts {
+    fn handle(&self, _: Vec<RaisedAlert>, _: &mut Commands) {}
+}
+
+/// The median number of allocations an update makes on this thread, after warming up.
+fn update_allocations(app: &mut App) -> usize {
+    fn single_threaded(schedule: &mut Schedule) {
+        schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+    }
+    app.edit_schedule(First, single_threaded)
+        .edit_schedule(PreUpdate, single_threaded)
+        .edit_schedule(Update, single_threaded)
+        .edit_schedule(PostUpdate, single_threaded)
+        .edit_schedule(Last, single_threaded);
+    for _ in 0..3 {
+        app.update();
+    }
+    let mut allocations = (0..9)
+        .map(|_| {
+            let before = ALLOCATIONS.with(|count| count.get());
+            app.update();
+            ALLOCATIONS.with(|count| count.get()) - before
+        })
+        .collect::<Vec<_>>();
+    allocations.sort();
+    allocations[4]
+}
+
+/// The allocations an update of an app running `system` makes, see [`update_allocations`].
+fn system_allocations<M>(system: impl IntoSystemConfigs<M>) -> usize {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AlertsPlugin::new().with_backend(DropAlerts)));
+    app.add_systems(Update, system);
+    update_allocations(&mut app)
+}
+
+fn save() -> Result<(), String> {
+    Ok(())
+}
+
+fn disk_full() -> Result<(), String> {
+    Err("disk full".to_string())
+}
+
+fn import_rows() -> ResultVec<(), String> {
+    Err(vec!["row 1 is invalid".to_string(); 3])
+}
+
+#[test]
+fn test_repeated_alert_allocations() {
+    let quiet = system_allocations(|_: Alerts| {});
+    let repeating = system_allocations(|mut alerts: Alerts| alerts.push("disk full"));
+    // only the lists of messages and alerts are allocated, not the message itself
+    assert!(
+        repeating.saturating_sub(quiet) <= 2,
+        "a repeated alert made {repeating} allocations, against {quiet} without it"
+    );
+}
+
+#[test]
+fn test_repeated_string_error_allocations() {
+    let quiet = system_allocations(save.anyhow_alert());
+    let repeating = system_allocations(disk_full.anyhow_alert());
+    // the error itself and its payload are allocated, besides the lists of messages and alerts
+    assert!(
+        repeating.saturating_sub(quiet) <= 4,
+        "a repeated error made {repeating} allocations, against {quiet} without it"
+    );
+}
+
+#[test]
+fn test_repeated_grouped_error_allocations() {
+    let quiet = system_allocations(save.anyhow_alert());
+    let repeating = system_allocations(import_rows.anyhow_alerts_grouped());
+    // the errors and the groups are allocated, besides the lists of messages and alerts, but
+    // not the messages themselves
+    assert!(
+        repeating.saturating_sub(quiet) <= 8,
+        "repeated grouped errors made {repeating} allocations, against {quiet} without them"
+    );
+}